@@ -0,0 +1,586 @@
+use std::collections::HashMap;
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use util::answers::{self, Answers};
+#[cfg(feature = "fetch")]
+use util::fetch::{FetchError, Fetched, InputCache};
+use util::image;
+use util::random::Rng;
+use util::scaffold;
+use util::timing::{self, Stats};
+use util::viz::{Animation, Frame};
+use util::{json, Answer, InputError, Usage};
+
+const USAGE: &str = "Usage:
+  aoc run <days> [--part 1|2] [--input <file | ->] [--input-dir <dir>] [--verify | --record] [--answers <file>] [--format table|json] [--jobs <count>]
+  aoc bench <days> [--part 1|2] [--input <file | ->] [--input-dir <dir>] [--runs <count>] [--format markdown|csv]
+  aoc fetch <days> [--input-dir <dir>] [--year <year>] [--base-url <url>]
+  aoc gen <day> [--size <size>] [--seed <seed>]
+  aoc viz <day> [--input <file | ->] [--input-dir <dir>] [--fps <rate> | --still] [--no-color]
+              [--save <dir> [--image png|ppm] [--scale <pixels>]]
+  aoc new <day> [--root <dir>]
+  aoc new-year <year> <dir>
+where <days> is all, a single day, first..last or first..=last";
+
+// the puzzles these solutions are for, which inputs are downloaded for
+#[cfg(feature = "fetch")]
+const YEAR: u32 = 2024;
+// fast enough to watch a simulation go by, slow enough to see what each step does
+const DEFAULT_FPS: f64 = 20.0;
+
+#[derive(PartialEq)]
+enum Command {
+    Run,
+    Bench,
+}
+
+#[derive(PartialEq)]
+enum Mode {
+    // print a table of answers
+    Table,
+    // compare answers against the answers file
+    Verify,
+    // save answers to the answers file as the new baseline
+    Record,
+}
+
+#[derive(PartialEq)]
+enum Format {
+    // run prints a table or one JSON object per answer
+    Table,
+    Json,
+    // bench prints a Markdown table or CSV
+    Markdown,
+    Csv,
+}
+
+struct Options {
+    days: Vec<usize>,
+    part: Option<usize>,
+    // a single input file (or "-" for stdin) only makes sense when running one day
+    input_file: Option<String>,
+    input_dir: PathBuf,
+    mode: Mode,
+    // defaults to answers.toml in the input directory, since the answers belong to those inputs
+    answers_file: Option<PathBuf>,
+    runs: usize,
+    // days run at the same time, one at a time unless asked otherwise so timings aren't disturbed
+    jobs: usize,
+    format: Format,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = match args.first().map(|it| it.as_str()) {
+        Some("run") => Command::Run,
+        Some("bench") => Command::Bench,
+        Some("fetch") => return fetch(&args[1..]),
+        Some("gen") => return generate(&args[1..]),
+        Some("viz") => return visualize(&args[1..]),
+        Some("new") => return new_day(&args[1..]),
+        Some("new-year") => return new_year(&args[1..]),
+        _ => exit_with_usage(),
+    };
+    let options = parse_options(&command, &args[1..]).unwrap_or_else(|| exit_with_usage());
+    match command {
+        Command::Run => run(options),
+        Command::Bench => bench(options),
+    }
+}
+
+fn parse_options(command: &Command, args: &[String]) -> Option<Options> {
+    let mut options = Options {
+        days: parse_days(args.first()?)?,
+        part: None,
+        input_file: None,
+        input_dir: util::input_dir(),
+        mode: Mode::Table,
+        answers_file: None,
+        runs: 10,
+        jobs: 1,
+        format: if *command == Command::Run { Format::Table } else { Format::Markdown },
+    };
+    let mut remaining = args[1..].iter();
+    while let Some(arg) = remaining.next() {
+        match (command, arg.as_str()) {
+            (_, "--part") => {
+                options.part = match remaining.next()?.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return None,
+                };
+            },
+            (_, "--input") => { options.input_file = Some(remaining.next()?.to_owned()); },
+            (_, "--input-dir") => { options.input_dir = PathBuf::from(remaining.next()?); },
+            (Command::Run, "--verify") if options.mode == Mode::Table => { options.mode = Mode::Verify; },
+            (Command::Run, "--record") if options.mode == Mode::Table => { options.mode = Mode::Record; },
+            (Command::Run, "--jobs") => {
+                options.jobs = remaining.next()?.parse().ok().filter(|it| *it > 0)?;
+            },
+            (Command::Run, "--answers") => { options.answers_file = Some(PathBuf::from(remaining.next()?)); },
+            (Command::Run, "--format") => {
+                options.format = match remaining.next()?.as_str() {
+                    "table" => Format::Table,
+                    "json" => Format::Json,
+                    _ => return None,
+                };
+            },
+            (Command::Bench, "--runs") => {
+                options.runs = remaining.next()?.parse().ok().filter(|it| *it > 0)?;
+            },
+            (Command::Bench, "--format") => {
+                options.format = match remaining.next()?.as_str() {
+                    "markdown" => Format::Markdown,
+                    "csv" => Format::Csv,
+                    _ => return None,
+                };
+            },
+            _ => return None,
+        }
+    }
+    if options.input_file.is_some() && options.days.len() != 1 {
+        return None;
+    }
+    // verify and record have their own output
+    if options.format == Format::Json && options.mode != Mode::Table {
+        return None;
+    }
+    return Some(options);
+}
+
+fn run(options: Options) {
+    let mode = options.mode;
+    let answers_file = options.answers_file.unwrap_or_else(|| options.input_dir.join(answers::ANSWERS_FILE));
+    let mut answers = if mode == Mode::Table { Answers::default() } else {
+        Answers::load(&answers_file).unwrap_or_else(|why| {
+            eprintln!("{}", why);
+            process::exit(1);
+        })
+    };
+
+    // print each row as soon as the day finishes so long runs show progress
+    let json = options.format == Format::Json;
+    if !json {
+        if mode == Mode::Verify {
+            println!("{:>3} | {:>4} | {:<7} | Answer", "Day", "Part", "Result");
+        }
+        else {
+            println!("{:>3} | {:<20} | {:<20}", "Day", "Part 1", "Part 2");
+        }
+        println!("{}", "-".repeat(49));
+    }
+    let mut failed = false;
+    let mut tally: [usize; 3] = [0, 0, 0];
+    let (part, input_file, input_dir) = (options.part, options.input_file.as_deref(), options.input_dir.as_path());
+    // the allocation counters are shared by every thread, so days running at once would count each
+    // other's allocations
+    let jobs = if util::alloc::is_counting() { 1 } else { options.jobs };
+    for_each_day(&options.days, jobs, |day| run_day(day, part, input_file, input_dir), |day, run| {
+        // a bad input only fails its own day, the rest still run
+        let run = match run {
+            Ok(run) => run,
+            Err(why) => {
+                if json {
+                    println!("{}", json::error_line(day, &why.to_string()));
+                }
+                else if mode == Mode::Verify {
+                    println!("{:>3} | {:>4} | {:<7} | see below", day, "-", "error");
+                }
+                else {
+                    println!("{:>3} | {:<20} | {:<20}", day, "error", "error");
+                }
+                eprintln!("Day {}: {}", day, why);
+                failed = true;
+                return;
+            },
+        };
+        if json {
+            for (index, (answer, elapsed)) in run.answers.iter().zip(run.part_times).enumerate() {
+                let (Some(answer), Some(elapsed)) = (answer, elapsed) else { continue; };
+                println!("{}", json::answer_line(day, index + 1, answer, elapsed, run.part_usage[index]));
+            }
+        }
+        else if mode != Mode::Verify {
+            println!("{:>3} | {:<20} | {:<20}", day, cell(run.answers[0].as_ref()), cell(run.answers[1].as_ref()));
+        }
+        for (index, result) in run.answers.into_iter().enumerate() {
+            // parts that weren't requested have nothing to check or record
+            let Some(answer) = result else { continue; };
+            // a part without an answer fails the run, and keeps whatever answer was recorded before
+            if let Answer::Unsolved(why) = answer {
+                if mode == Mode::Verify && !json {
+                    println!("{:>3} | {:>4} | {:<7} | see below", day, index + 1, "error");
+                }
+                eprintln!("Day {} part {}: {}", day, index + 1, why);
+                failed = true;
+                continue;
+            }
+            match mode {
+                Mode::Table => {},
+                Mode::Record => answers.set(day, index + 1, answer),
+                Mode::Verify => {
+                    let (result, detail) = match answers.get(day, index + 1) {
+                        Some(expected) if *expected == answer => { tally[0] += 1; ("pass", answer.to_string()) },
+                        Some(expected) => { tally[1] += 1; ("FAIL", format!("got {}, expected {}", answer, expected)) },
+                        None => { tally[2] += 1; ("missing", answer.to_string()) },
+                    };
+                    println!("{:>3} | {:>4} | {:<7} | {}", day, index + 1, result, detail);
+                },
+            }
+        }
+    });
+
+    match mode {
+        Mode::Table => {},
+        Mode::Record => {
+            if let Err(why) = answers.save(&answers_file) {
+                eprintln!("Failed to write {}: {}", answers_file.display(), why);
+                process::exit(1);
+            }
+            println!("Recorded answers in {}", answers_file.display());
+        },
+        Mode::Verify => {
+            println!("{} passed, {} failed, {} missing", tally[0], tally[1], tally[2]);
+            failed |= tally[1] > 0;
+        },
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn bench(options: Options) {
+    // built with the alloc-stats feature, the allocations of the last run are shown as well
+    let counting = util::alloc::is_counting();
+    match options.format {
+        Format::Markdown if counting => {
+            println!("| Day | Step   | Runs |        Min |     Median |        Max | Allocations |       Peak |");
+            println!("|----:|--------|-----:|-----------:|-----------:|-----------:|------------:|-----------:|");
+        },
+        Format::Markdown => {
+            println!("| Day | Step   | Runs |        Min |     Median |        Max |");
+            println!("|----:|--------|-----:|-----------:|-----------:|-----------:|");
+        },
+        _ if counting => println!("day,step,runs,min_ns,median_ns,max_ns,allocations,peak_bytes"),
+        _ => println!("day,step,runs,min_ns,median_ns,max_ns"),
+    }
+    let mut failed = false;
+    for day in options.days {
+        // only time the solving, the input is read once up front
+        let samples = read_day(day, options.input_file.as_deref(), &options.input_dir).and_then(|(input, path)| {
+            let solver = advent_of_code_2024::solver(day).unwrap();
+            // parse, part 1 and part 2
+            let mut samples: [Vec<Duration>; 3] = [vec![], vec![], vec![]];
+            let mut usage: [Option<Usage>; 3] = [None; 3];
+            for _ in 0..options.runs {
+                let run = solver(&input, options.part).map_err(|why| why.with_path(&path))?;
+                samples[0].push(run.parse_time);
+                for (index, time) in run.part_times.into_iter().enumerate() {
+                    samples[index + 1].extend(time);
+                }
+                usage = [run.parse_usage, run.part_usage[0], run.part_usage[1]];
+            }
+            Ok((samples, usage))
+        });
+        let (samples, usage) = match samples {
+            Ok(samples) => samples,
+            Err(why) => {
+                eprintln!("Day {}: {}", day, why);
+                failed = true;
+                continue;
+            },
+        };
+        // rows are printed as each day finishes so long runs show progress
+        for ((step, samples), usage) in ["parse", "part 1", "part 2"].into_iter().zip(samples).zip(usage) {
+            let Some(stats) = Stats::from_samples(&samples) else { continue; };
+            match options.format {
+                Format::Markdown => println!(
+                    "| {:>3} | {:<6} | {:>4} | {:>10} | {:>10} | {:>10} |{}",
+                    day, step, stats.runs, timing::format_duration(stats.min), timing::format_duration(stats.median), timing::format_duration(stats.max),
+                    usage.map(|it| format!(" {:>11} | {:>10} |", it.allocations, format_bytes(it.peak_bytes))).unwrap_or_default(),
+                ),
+                _ => println!(
+                    "{},{},{},{},{},{}{}", day, step, stats.runs, stats.min.as_nanos(), stats.median.as_nanos(), stats.max.as_nanos(),
+                    usage.map(|it| format!(",{},{}", it.allocations, it.peak_bytes)).unwrap_or_default(),
+                ),
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+// peak memory in the largest unit that keeps it at least 1, e.g. 1.5 MiB
+fn format_bytes(bytes: usize) -> String {
+    let mut value = bytes as f64;
+    for unit in ["B", "KiB", "MiB"] {
+        if value < 1024.0 {
+            return if unit == "B" { format!("{} B", bytes) } else { format!("{:.1} {}", value, unit) };
+        }
+        value /= 1024.0;
+    }
+    return format!("{:.1} GiB", value);
+}
+
+#[cfg(not(feature = "fetch"))]
+fn fetch(_args: &[String]) {
+    eprintln!("This aoc was built without the fetch feature, so it can't download inputs");
+    process::exit(1);
+}
+
+#[cfg(feature = "fetch")]
+fn fetch(args: &[String]) {
+    let days = args.first().and_then(|it| parse_days(it)).unwrap_or_else(|| exit_with_usage());
+    let mut input_dir = util::input_dir();
+    let mut year = YEAR;
+    let mut base_url: Option<String> = None;
+    let mut remaining = args[1..].iter();
+    while let Some(arg) = remaining.next() {
+        let value = remaining.next().unwrap_or_else(|| exit_with_usage());
+        match arg.as_str() {
+            "--input-dir" => input_dir = PathBuf::from(value),
+            "--year" => year = value.parse().unwrap_or_else(|_| exit_with_usage()),
+            "--base-url" => base_url = Some(value.to_owned()),
+            _ => exit_with_usage(),
+        }
+    }
+
+    let mut cache = InputCache::new(&input_dir, year);
+    if let Some(base_url) = base_url {
+        cache = cache.with_base_url(&base_url);
+    }
+    let mut failed = false;
+    for day in days {
+        match cache.fetch(day) {
+            Ok(Fetched::Downloaded) => println!("Day {}: downloaded {}", day, cache.path(day).display()),
+            Ok(Fetched::Cached) => println!("Day {}: already in {}", day, cache.path(day).display()),
+            Err(why) => {
+                eprintln!("Day {}: {}", day, why);
+                failed = true;
+                // without a session none of the other days will work either
+                if matches!(why, FetchError::NoSession) { break; }
+            },
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn generate(args: &[String]) {
+    let day: usize = args.first().and_then(|it| it.parse().ok()).unwrap_or_else(|| exit_with_usage());
+    let (generator, mut size) = advent_of_code_2024::generator(day).unwrap_or_else(|| exit_with_usage());
+    let mut seed: Option<u64> = None;
+    let mut remaining = args[1..].iter();
+    while let Some(arg) = remaining.next() {
+        let value = remaining.next().unwrap_or_else(|| exit_with_usage());
+        match arg.as_str() {
+            "--size" => size = value.parse().unwrap_or_else(|_| exit_with_usage()),
+            "--seed" => seed = Some(value.parse().unwrap_or_else(|_| exit_with_usage())),
+            _ => exit_with_usage(),
+        }
+    }
+
+    // a new input every time unless a seed is given, the seed goes to stderr so it can be made again
+    let seed = seed.unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).map(|it| it.as_nanos() as u64).unwrap_or_default());
+    eprintln!("Day {} with size {} and seed {}", day, size, seed);
+    print!("{}", generator(&mut Rng::new(seed), size));
+}
+
+fn visualize(args: &[String]) {
+    let day: usize = args.first().and_then(|it| it.parse().ok()).unwrap_or_else(|| exit_with_usage());
+    let Some(visualizer) = advent_of_code_2024::visualizer(day) else {
+        eprintln!("Day {} has nothing to show, only the days on a grid do (6, 10, 12, 14, 15, 16, 18 and 20)", day);
+        process::exit(2);
+    };
+    let mut input_file: Option<String> = None;
+    let mut input_dir = util::input_dir();
+    let mut fps: Option<f64> = None;
+    let mut still = false;
+    let mut color: Option<bool> = None;
+    // images instead of the terminal
+    let mut save_dir: Option<PathBuf> = None;
+    let mut image_format = image::Format::Png;
+    let mut scale = image::DEFAULT_SCALE;
+    let mut remaining = args[1..].iter();
+    while let Some(arg) = remaining.next() {
+        match arg.as_str() {
+            "--still" => still = true,
+            "--no-color" => color = Some(false),
+            _ => {
+                let value = remaining.next().unwrap_or_else(|| exit_with_usage());
+                match arg.as_str() {
+                    "--input" => input_file = Some(value.to_owned()),
+                    "--input-dir" => input_dir = PathBuf::from(value),
+                    "--fps" => fps = Some(value.parse().ok().filter(|it| *it > 0.0).unwrap_or_else(|| exit_with_usage())),
+                    "--save" => save_dir = Some(PathBuf::from(value)),
+                    "--image" => image_format = match value.as_str() {
+                        "png" => image::Format::Png,
+                        "ppm" => image::Format::Ppm,
+                        _ => exit_with_usage(),
+                    },
+                    "--scale" => scale = value.parse().ok().filter(|it| *it > 0).unwrap_or_else(|| exit_with_usage()),
+                    _ => exit_with_usage(),
+                }
+            },
+        }
+    }
+    // frames are either watched at some speed or saved, and --still shows just the last one
+    if still && fps.is_some() || save_dir.is_some() && (fps.is_some() || color.is_some()) {
+        exit_with_usage();
+    }
+
+    let frames = read_day(day, input_file.as_deref(), &input_dir)
+        .and_then(|(input, path)| visualizer(&input).map_err(|why| why.with_path(&path)))
+        .unwrap_or_else(|why| {
+            eprintln!("Day {}: {}", day, why);
+            process::exit(1);
+        });
+    if let Some(dir) = save_dir {
+        let frames: Box<dyn Iterator<Item = Frame>> = if still { Box::new(frames.last().into_iter()) } else { frames };
+        match image::save_frames(frames, &dir, image_format, scale) {
+            Ok(paths) => println!("Wrote {} frames to {}", paths.len(), dir.display()),
+            Err(why) => {
+                eprintln!("Day {}: {}: {}", day, dir.display(), why);
+                process::exit(1);
+            },
+        }
+        return;
+    }
+
+    let mut animation = if still { Animation::still() } else { Animation::new(fps.unwrap_or(DEFAULT_FPS)) };
+    if let Some(color) = color {
+        animation = animation.with_color(color);
+    }
+    match animation.play(frames, &mut io::stdout().lock()) {
+        // closing the pager or pressing ctrl-c part way through is how most animations end
+        Err(why) if why.kind() == io::ErrorKind::BrokenPipe => {},
+        Err(why) => {
+            eprintln!("Day {}: {}", day, why);
+            process::exit(1);
+        },
+        Ok(_) => {},
+    }
+}
+
+fn new_day(args: &[String]) {
+    let (day, root) = match args {
+        [day] => (day, "."),
+        [day, flag, root] if flag == "--root" => (day, root.as_str()),
+        _ => exit_with_usage(),
+    };
+    let day: usize = day.parse().unwrap_or_else(|_| exit_with_usage());
+    report_created(scaffold::new_day(Path::new(root), day));
+}
+
+fn new_year(args: &[String]) {
+    let [year, root] = args else { exit_with_usage(); };
+    let year: u32 = year.parse().unwrap_or_else(|_| exit_with_usage());
+    // the new year shares the util crate from this checkout
+    let util = Path::new(env!("CARGO_MANIFEST_DIR")).join("util");
+    report_created(scaffold::new_year(Path::new(root), year, &util));
+}
+
+fn report_created(result: io::Result<Vec<PathBuf>>) {
+    match result {
+        Ok(paths) => paths.iter().for_each(|path| println!("Wrote {}", path.display())),
+        Err(why) => {
+            eprintln!("{}", why);
+            process::exit(1);
+        },
+    }
+}
+
+fn read_day(day: usize, input_file: Option<&str>, input_dir: &Path) -> Result<(String, PathBuf), InputError> {
+    // the path is kept so parse errors can point at the file
+    return match input_file {
+        Some("-") => Ok((util::try_read_stdin()?, PathBuf::from(util::STDIN_NAME))),
+        Some(path) => Ok((util::try_read_path(Path::new(path))?, PathBuf::from(path))),
+        None => {
+            let path = input_dir.join(util::input_file(day));
+            Ok((util::try_read_path(&path)?, path))
+        },
+    };
+}
+
+// does the work for each day on up to jobs threads, but reports the results in the order of the days
+// as soon as every day before them has been reported
+fn for_each_day<T: Send>(days: &[usize], jobs: usize, work: impl Fn(usize) -> T + Sync, mut report: impl FnMut(usize, T)) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let (next, sender, work) = (&next, sender.clone(), &work);
+            scope.spawn(move || {
+                // each worker takes the next day nobody has started, so slow days don't hold up a queue
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(day) = days.get(index) else { break; };
+                    if sender.send((index, work(*day))).is_err() { break; }
+                }
+            });
+        }
+        drop(sender);
+
+        // results that came in before an earlier day's, by their index in days
+        let mut finished: HashMap<usize, T> = HashMap::new();
+        let mut reported = 0;
+        for (index, result) in receiver {
+            finished.insert(index, result);
+            while let Some(result) = finished.remove(&reported) {
+                report(days[reported], result);
+                reported += 1;
+            }
+        }
+    });
+}
+
+fn run_day(day: usize, part: Option<usize>, input_file: Option<&str>, input_dir: &Path) -> Result<util::Run, InputError> {
+    let solver = advent_of_code_2024::solver(day).unwrap();
+    let (input, path) = read_day(day, input_file, input_dir)?;
+    return solver(&input, part).map_err(|why| why.with_path(&path));
+}
+
+fn cell(answer: Option<&Answer>) -> String {
+    // parts that weren't requested are left as a dash, the reason a part failed is printed after
+    return match answer {
+        Some(Answer::Unsolved(_)) => "error".to_owned(),
+        Some(answer) => answer.to_string(),
+        None => "-".to_owned(),
+    };
+}
+
+fn parse_days(spec: &str) -> Option<Vec<usize>> {
+    // accepts "all", a single day, or an exclusive/inclusive range of days
+    let days: Vec<usize> = if spec == "all" {
+        (1..=25).collect()
+    }
+    else if let Some((first, last)) = spec.split_once("..=") {
+        (first.parse().ok()?..=last.parse().ok()?).collect()
+    }
+    else if let Some((first, last)) = spec.split_once("..") {
+        (first.parse().ok()?..last.parse().ok()?).collect()
+    }
+    else {
+        vec![spec.parse().ok()?]
+    };
+
+    // make sure every requested day actually has a solver
+    if days.is_empty() || days.iter().any(|day| advent_of_code_2024::solver(*day).is_none()) {
+        return None;
+    }
+    return Some(days);
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
@@ -1,146 +1,65 @@
-use util;
+use std::env;
+use std::io;
+use std::process;
 
-struct Registers {
-    a: i64,
-    b: i64,
-    c: i64
-}
-
-/* my program input simplifies to
-*   b <- a % 8
-*   b <- b ^ 1
-*   c <- a / 2^b
-*   a <- a / 8
-*   b <- b ^ c
-*   output b % 8
-*   jump 0 if a != 0
-*/
+use advent_of_code_2024::day17::asm;
+use advent_of_code_2024::day17::debugger::Debugger;
+use advent_of_code_2024::day17::Day17;
+use util::Solution;
 
 fn main() {
-    let mut lines = util::read_lines("day17.txt").into_iter();
-    // populate registers
-    let a = parse_register(lines.next().unwrap()); 
-    let b = parse_register(lines.next().unwrap()); 
-    let c = parse_register(lines.next().unwrap()); 
-    let mut registers = Registers { a, b, c };
-    // consume the blank line
-    lines.next();
-    // populate the list of instructions
-    let line = lines.next().unwrap();
-    let (_, program) = line.split_once(": ").unwrap();
-    let instructions: Vec<i64> = program.split(",").map(|it| it.parse::<i64>().unwrap()).collect();
-
-    println!("Part 1: {}", part_1(&mut registers, &instructions).iter().map(|it| it.to_string()).collect::<Vec<_>>().join(","));
-    println!("Part 2: {}", part_2(&instructions, instructions.as_slice()));
-}
-
-fn part_1(registers: &mut Registers, instructions: &Vec<i64>) -> Vec<i64> {
-    let mut output: Vec<i64> = vec![];
-    let mut instruction_pointer: i64 = 0;
-
-    while let Some(opcode) = instructions.get(instruction_pointer as usize) {
-        let operand = instructions.get(instruction_pointer as usize + 1).unwrap();
-        match opcode {
-            0 => { adv(registers, *operand, &mut instruction_pointer); },
-            1 => { bxl(registers, *operand, &mut instruction_pointer); },
-            2 => { bst(registers, *operand, &mut instruction_pointer); },
-            3 => { jnz(registers, *operand, &mut instruction_pointer); },
-            4 => { bxc(registers, *operand, &mut instruction_pointer); },
-            5 => { output.push(out(registers, *operand, &mut instruction_pointer)); },
-            6 => { bdv(registers, *operand, &mut instruction_pointer); },
-            7 => { cdv(registers, *operand, &mut instruction_pointer); },
-            _ => { panic!("Unknown opcode"); },
-        }
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    match arguments.first().map(String::as_str) {
+        Some("--disasm") => disassemble(arguments.get(1).map(String::as_str)),
+        Some("--asm") => assemble(arguments.get(1).map(String::as_str)),
+        Some("--debug") => debug(arguments.get(1).map(String::as_str)),
+        _ => util::run::<Day17>(17),
     }
-    return output;
 }
 
-fn part_2(instructions: &Vec<i64>, target: &[i64]) -> i64 {
-    // only value of a matters for each iteration
-    // last 3 bits of a are removed each iteration
-    
-    // start from 0 if we're looking for one number
-    // otherwise, start from 8 * answer for list with first item removed
-    let mut value = if target.len() == 1 { 0 } else { 8 * part_2(instructions, &target[1..]) };
-    
-    while target != part_1(&mut Registers { a: value, b: 0, c: 0 }, instructions) {
-        value += 1;
+// prints the program from the input as assembly instead of solving the puzzle
+fn disassemble(argument: Option<&str>) {
+    let filename = util::input_file(17);
+    let program = util::try_read_input(&filename, argument)
+        .and_then(|input| asm::find_program(&input).map_err(|why| why.with_path(&util::input_path(&filename, argument))));
+    match program {
+        Ok(program) => print!("{}", asm::disassemble(&program)),
+        Err(why) => exit_with_error(why),
     }
-    
-    return value;
-}
-
-fn adv(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
-    // performs division of a and combo operand and writes into a
-    let numerator = registers.a;
-    let denominator = 2i64.pow(get_combo_operand(registers, operand) as u32);
-    registers.a = numerator / denominator;
-    *instruction_pointer += 2;
-}
-
-fn bxl(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
-    // performs bitwise xor of b and operand and writes into b
-    registers.b = registers.b ^ operand;
-    *instruction_pointer += 2;
-}
-
-fn bst(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
-    // calculates combo operand mod 8 and writes into b
-    registers.b = get_combo_operand(registers, operand) % 8;
-    *instruction_pointer += 2;
 }
 
-fn jnz(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
-    // do nothing if a is 0, otherwise jump to literal operand
-    if registers.a != 0 {
-        *instruction_pointer = operand;
-    }
-    else {
-        *instruction_pointer += 2;
+// prints the "Program: ..." line for an assembly source, which has to be given
+fn assemble(argument: Option<&str>) {
+    let Some(argument) = argument else {
+        eprintln!("Usage: day17 --asm <file | ->");
+        process::exit(2);
+    };
+    let path = util::input_path("", Some(argument));
+    let program = util::try_read_input("", Some(argument)).and_then(|source| asm::assemble(&source).map_err(|why| why.with_path(&path)));
+    match program {
+        Ok(program) => println!("Program: {}", asm::format_program(&program)),
+        Err(why) => exit_with_error(why),
     }
 }
 
-fn bxc(registers: &mut Registers, _operand: i64, instruction_pointer: &mut i64) {
-    // performs bitwise xor of b and c and writes into c
-    // ignores the operand
-    registers.b = registers.b ^ registers.c;
-    *instruction_pointer += 2;
-}
-
-fn out(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) -> i64 {
-    // calculates combo operand mod 8 and outputs it
-    *instruction_pointer += 2;
-    return get_combo_operand(registers, operand) % 8;
-}
-
-fn bdv(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
-    // adv but store into b
-    let numerator = registers.a;
-    let denominator = 2i64.pow(get_combo_operand(registers, operand) as u32);
-    registers.b = numerator / denominator;
-    *instruction_pointer += 2;
-}
-
-fn cdv(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
-    // adv but store into c
-    let numerator = registers.a;
-    let denominator = 2i64.pow(get_combo_operand(registers, operand) as u32);
-    registers.c = numerator / denominator;
-    *instruction_pointer += 2;
-}
-
-fn get_combo_operand(registers: &Registers, operand: i64) -> i64 {
-    return match operand {
-        // 0-3 are literal values
-        0 | 1 | 2 | 3 => operand,
-        4 => registers.a,
-        5 => registers.b,
-        6 => registers.c,
-        _ => panic!("Unknown combo operator")
+// steps through the puzzle's program with commands from stdin, which is why the input can't come from there
+fn debug(argument: Option<&str>) {
+    if argument == Some("-") {
+        eprintln!("The debugger reads commands from stdin, so the input has to be a file");
+        process::exit(2);
+    }
+    let filename = util::input_file(17);
+    let day = util::try_read_input(&filename, argument)
+        .and_then(|input| Day17::parse(&input).map_err(|why| why.with_path(&util::input_path(&filename, argument))))
+        .unwrap_or_else(|why| exit_with_error(why));
+    let mut debugger = Debugger::new(day.vm());
+    if let Err(why) = debugger.run(io::stdin().lock(), &mut io::stdout()) {
+        eprintln!("{}", why);
+        process::exit(1);
     }
 }
 
-fn parse_register(line: String) -> i64 {
-    let (_, register) = line.split_once(": ").unwrap();
-    return register.parse().unwrap();
+fn exit_with_error(why: util::InputError) -> ! {
+    eprintln!("{}", why);
+    process::exit(1);
 }
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use util::random::Rng;
+use util::{Answer, InputError, Solution};
+
+pub struct Day01 {
+    list1: Vec<i32>,
+    list2: Vec<i32>,
+}
+
+impl Solution for Day01 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        // initialize two empty vectors, one for each column
+        let mut list1: Vec<i32> = vec![];
+        let mut list2: Vec<i32> = vec![];
+
+        for line in util::numbered_lines(input) {
+            // split and map to a vector of ints
+            let numbers: Vec<&str> = line.text.split_whitespace().collect();
+            if numbers.len() != 2 {
+                return Err(InputError::on_line(line.number, format!("Expected two location ids but found {}", numbers.len())));
+            }
+            // add the ints to the lists
+            list1.push(line.parse(numbers[0])?);
+            list2.push(line.parse(numbers[1])?);
+        }
+
+        list1.sort();
+        list2.sort();
+        return Ok(Day01 { list1, list2 });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&self.list1, &self.list2).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(&self.list1, &self.list2).into()
+    }
+}
+
+fn part_1(list1: &[i32], list2: &[i32]) -> i32 {
+    let mut sum: i32 = 0;
+    for (one, two) in list1.iter().zip(list2) {
+        sum += (one - two).abs()
+    }
+    return sum;
+}
+
+fn part_2(list1: &[i32], list2: &[i32]) -> i32 {
+    let mut map = HashMap::new();
+    // create map from number -> occurrences of that number
+    for number in list2.iter() {
+        match map.get(number) {
+            Some(result) => map.insert(number, result + 1),
+            _ => map.insert(number, 1),
+        };
+    }
+
+    let mut similarity: i32 = 0;
+    // calculate similarity based on the number of occurrences of that number
+    for number in list1.iter() {
+        similarity += number * map.get(number).unwrap_or(&0);
+    }
+    return similarity;
+}
+
+// two columns of 5 digit location ids, about a third of the right ones copied from the left
+pub fn generate(rng: &mut Rng, lines: usize) -> String {
+    let left: Vec<i64> = (0..lines).map(|_| rng.range(10000..=99999)).collect();
+    let mut output = String::new();
+    for id in &left {
+        let right = if rng.chance(0.3) { *rng.pick(&left) } else { rng.range(10000..=99999) };
+        output.push_str(&format!("{}   {}\n", id, right));
+    }
+    return output;
+}
@@ -0,0 +1,133 @@
+use util::random::Rng;
+use util::{Answer, InputError, Solution};
+
+pub struct Day02 {
+    reports: Vec<Vec<i32>>,
+}
+
+impl Solution for Day02 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut reports: Vec<Vec<i32>> = vec![];
+        for line in util::numbered_lines(input) {
+            // split and map to a vector of ints
+            let numbers: Vec<i32> = line.text.split_whitespace().map(|x| line.parse::<i32>(x)).collect::<Result<_, _>>()?;
+            // the direction of a report is decided by its first two levels
+            if numbers.len() < 2 {
+                return Err(InputError::on_line(line.number, "Expected at least two levels in the report"));
+            }
+            reports.push(numbers);
+        }
+        return Ok(Day02 { reports });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&self.reports).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(&self.reports).into()
+    }
+}
+
+fn part_1(reports: &[Vec<i32>]) -> i32 {
+    let mut safe_reports: i32 = 0;
+    for report in reports.iter() {
+        if check_report(report.to_vec()) {
+            safe_reports += 1;
+        }
+    }
+    return safe_reports;
+}
+
+fn part_2(reports: &[Vec<i32>]) -> i32 {
+    let mut safe_reports: i32 = 0;
+    for report in reports.iter() {
+        if check_report_with_dampener(report.to_vec()) {
+            safe_reports += 1;
+        }
+    }
+    return safe_reports;
+}
+
+fn check_report(report: Vec<i32>) -> bool {
+    // a report is safe if it always increases or always decreases and change is at least 1 and
+    // no more than 3
+    // the dampener can leave a single level, which has nothing to compare so is always safe
+    if report.len() < 2 {
+        return true;
+    }
+
+    let mut previous: i32 = report[0];
+    let increasing: bool = report[1] - report[0] > 0; 
+
+    // skip the first one because there's nothing to compare it to
+    for level in report.iter().skip(1) {
+        if ((level - previous).abs() < 1 || (level - previous).abs() > 3) ||
+        (increasing && level - previous < 0 ) ||
+        (!increasing && level - previous > 0) {
+            return false;
+        }
+        previous = *level;
+    }
+    return true;
+}
+
+fn check_report_with_dampener(report: Vec<i32>) -> bool {
+    if check_report(report.to_vec()) {
+        return true;
+    }
+
+    // a report is safe if it always increases or always decreases and change is at least 1 and
+    // no more than 3
+    // additionally, one level may be removed by the dampener
+    
+    // edge case: check for removing the very first or second element
+    // as these can determine the value of increasing
+    let mut remove_two = report.to_vec();
+    remove_two.remove(1);
+    if check_report(report[1..].to_vec()) || check_report(remove_two) {
+        return true;
+    }
+
+    let mut previous: i32 = report[1];
+    let increasing: bool = report[1] - report[0] > 0; 
+
+    // skip the first two because they have been checked already
+    for (index, level) in report.iter().skip(2).enumerate() {
+        if ((level - previous).abs() < 1 || (level - previous).abs() > 3) ||
+        (increasing && level - previous < 0 ) ||
+        (!increasing && level - previous > 0) {
+            // try removing this element and the one before it, add 2 because of skip
+            let mut attempt1 = report.to_vec();
+            attempt1.remove(index + 2);
+            let mut attempt2 = report.to_vec();
+            attempt2.remove(index + 1);
+            if !check_report(attempt1) && !check_report(attempt2) {
+                return false;
+            }
+        }
+        previous = *level;
+    }
+    return true;
+}
+
+// reports of 5 to 8 levels that mostly change gradually, some with a level out of place
+pub fn generate(rng: &mut Rng, reports: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..reports {
+        let direction = if rng.chance(0.5) { 1 } else { -1 };
+        let mut level = rng.range(10..=90);
+        let mut levels: Vec<i64> = vec![];
+        for _ in 0..rng.range(5..=8) {
+            levels.push(level);
+            level += direction * rng.range(1..=3);
+        }
+        if rng.chance(0.4) {
+            let index = rng.below(levels.len());
+            levels[index] = rng.range(1..=99);
+        }
+        output.push_str(&levels.iter().map(|it| it.to_string()).collect::<Vec<_>>().join(" "));
+        output.push('\n');
+    }
+    return output;
+}
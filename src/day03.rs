@@ -0,0 +1,73 @@
+use regex::Regex;
+use util::random::Rng;
+use util::{Answer, InputError, Solution};
+
+pub struct Day03 {
+    input: String,
+}
+
+impl Solution for Day03 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        // the instructions may span multiple lines, treat them as one long line
+        return Ok(Day03 { input: input.lines().collect() });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&self.input).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(&self.input).into()
+    }
+}
+
+fn part_1(input: &str) -> i32 {
+    // match any valid mul() operation and save the two numbers into capture classes
+    let pattern: Regex = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+    let mut sum: i32 = 0;
+    for (_, [number1, number2]) in  pattern.captures_iter(input).map(|it| it.extract()) {
+        sum += number1.parse::<i32>().unwrap() * number2.parse::<i32>().unwrap();
+    }
+    return sum;
+}
+
+fn part_2(input: &str) -> i32 {
+    // same as before but also add "do" and "don't" as captures, to match capture number place both
+    // numbers into one capture and split later
+    let pattern: Regex = Regex::new(r"mul\(([0-9]{1,3},[0-9]{1,3})\)|(do)\(\)|(don't)\(\)").unwrap();
+    let mut sum: i32 = 0;
+    // begin with multiplication enabled until we see a "don't"
+    let mut enabled = true;
+    for (_, [capture]) in  pattern.captures_iter(input).map(|it| it.extract()) {
+        // check for a do or don't
+        if capture == "do" { enabled = true; }
+        else if capture == "don't" { enabled = false; }
+        else if enabled {
+            // only add to the sum if multiplication is currently "enabled"
+            let mut numbers = capture.split(",");
+            sum += numbers.next().unwrap().parse::<i32>().unwrap() * numbers.next().unwrap().parse::<i32>().unwrap();
+        }
+    }
+    return sum;
+}
+
+// corrupted memory, instructions with junk between them spread over a few long lines
+pub fn generate(rng: &mut Rng, instructions: usize) -> String {
+    const JUNK: [&str; 14] = ["mul(4*", "mul(6,9!", "?(12,34)", "mul ( 2 , 4 )", "from()", "select()", "#", "%&", "]", "who()", "mul[3,7]", "how()", "don't", "'"];
+    let mut output = String::new();
+    for count in 1..=instructions {
+        match rng.below(10) {
+            0 => output.push_str("do()"),
+            1 => output.push_str("don't()"),
+            _ => output.push_str(&format!("mul({},{})", rng.range(1..=999), rng.range(1..=999))),
+        }
+        for _ in 0..rng.below(4) {
+            let junk = rng.pick(&JUNK);
+            output.push_str(junk);
+        }
+        if count % 120 == 0 || count == instructions {
+            output.push('\n');
+        }
+    }
+    return output;
+}
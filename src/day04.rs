@@ -0,0 +1,99 @@
+use util::random::Rng;
+use util::{Answer, Grid, InputError, Point, Solution};
+
+pub struct Day04 {
+    grid: Grid<char>,
+}
+
+impl Solution for Day04 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        return Ok(Day04 { grid: util::try_parse_grid(input)? });
+    }
+
+    fn part_1(&self) -> Answer {
+        // only X's can start the word, so avoid iterating over the entire grid
+        part_1(&self.grid.positions_of(&'X').collect::<Vec<(usize, usize)>>(), &self.grid).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(&self.grid.positions_of(&'A').collect::<Vec<(usize, usize)>>(), &self.grid).into()
+    }
+}
+
+fn part_1(x_list: &[(usize, usize)], grid: &Grid<char>) -> i32 {
+    // define all possible directions from the X
+    let directions: [Point; 8] = [(0, 1), (0, -1), (1, 0), (-1, 0), (1, 1), (-1, 1), (1, -1), (-1, -1)].map(|(x, y)| Point::new(x, y));
+    let mut sum: i32 = 0;
+
+    for point in x_list.iter() {
+        for direction in directions.iter() {
+            if let Some(result) = get_points_in_line(Point::from(*point), *direction, grid) {
+                // rest of the letters must spell "MAS" in order
+                if grid[result[0]] == 'M' 
+                && grid[result[1]] == 'A' 
+                && grid[result[2]] == 'S' {
+                    sum += 1;
+                }
+            };
+        }
+    }
+    return sum;
+}
+
+fn part_2(a_list: &[(usize, usize)], grid: &Grid<char>) -> i32 {
+    let mut sum: i32 = 0;
+
+    for point in a_list.iter() {
+        if let Some(result) = get_points_in_x(Point::from(*point), grid) {
+            // "MAS" can be forwards or backwords when forming the 'X'
+            // check \ diagonal first
+            if ((grid[result[0]] == 'M' && grid[result[3]] == 'S')  ||
+            (grid[result[0]] == 'S' && grid[result[3]] == 'M'))
+            &&
+            // check / diagonal next
+            ((grid[result[1]] == 'M' && grid[result[2]] == 'S')  ||
+            (grid[result[1]] == 'S' && grid[result[2]] == 'M')) { 
+                sum += 1;
+            };
+        }
+    }
+    return sum;
+}
+
+fn get_points_in_line(point: Point, direction: Point, grid: &Grid<char>) -> Option<[Point; 3]> {
+    // given the position of an X and the direction to go in, get the next 3 points in the line
+    let m_point = point + direction;
+    let a_point = point + direction * 2;
+    let s_point = point + direction * 3;
+
+    let list = [m_point, a_point, s_point];
+    // make sure each point is actually on the grid
+    for item in list.iter() {
+        if !grid.contains(*item) {
+            return None;
+        }
+    }
+    Some(list)
+}
+
+fn get_points_in_x(point: Point, grid: &Grid<char>) -> Option<[Point; 4]> {
+    // given the position of an A, get the points in the corners that form an 'X' shape 
+    let upper_left = point + Point::new(-1, 1);
+    let upper_right = point + Point::new(1, 1);
+    let lower_left = point + Point::new(-1, -1);
+    let lower_right = point + Point::new(1, -1);
+
+    let list = [upper_left, upper_right, lower_left, lower_right];
+    // make sure each point is actually on the grid
+    for item in list.iter() {
+        if !grid.contains(*item) {
+            return None;
+        }
+    }
+    Some(list)
+}
+
+// a square word search of random X, M, A and S letters
+pub fn generate(rng: &mut Rng, side: usize) -> String {
+    return (0..side).map(|_| (0..side).map(|_| *rng.pick(&['X', 'M', 'A', 'S'])).chain(['\n']).collect::<String>()).collect();
+}
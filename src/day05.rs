@@ -0,0 +1,128 @@
+use std::collections::{HashMap, HashSet};
+use std::cmp::Ordering;
+
+use util::random::Rng;
+use util::{Answer, InputError, Solution};
+
+pub struct Day05 {
+    // map from each page number to all page numbers that must be printed BEFORE it
+    rules: HashMap<i32, HashSet<i32>>,
+    updates: Vec<Vec<i32>>,
+}
+
+impl Solution for Day05 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut lines = util::numbered_lines(input);
+        
+        let mut rules: HashMap<i32, HashSet<i32>> = HashMap::new();
+        let mut found_separator = false;
+        for line in lines.by_ref() {
+            // a blank line separates the rules from the updates
+            if line.text.trim().is_empty() {
+                found_separator = true;
+                break;
+            }
+            let (value, key) = line.split_once(line.text.trim(), "|")?;
+            let value: i32 = line.parse(value)?;
+            let key: i32 = line.parse(key)?;
+
+            match rules.get_mut(&key) {
+                // add new page to set or create set if needed
+                Some(set) => { set.insert(value); },
+                None => { rules.insert(key, HashSet::from([value])); },
+            }
+        }
+        if !found_separator {
+            return Err(InputError::missing("Expected a blank line between the rules and the updates"));
+        }
+
+        // get a Vec of the pages as ints for each update
+        let mut updates: Vec<Vec<i32>> = vec![];
+        for line in lines {
+            let pages: Vec<i32> = line.text.trim().split(",").map(|number| line.parse::<i32>(number)).collect::<Result<_, _>>()?;
+            updates.push(pages);
+        }
+        return Ok(Day05 { rules, updates });
+    }
+
+    fn part_1(&self) -> Answer {
+        let (valid_updates, _) = partition_updates(&self.rules, &self.updates);
+        let mut sum: i32 = 0;
+        for pages in valid_updates {
+            // this update was good, add its middle page
+            sum += pages[pages.len() / 2];
+        }
+        return sum.into();
+    }
+
+    fn part_2(&self) -> Answer {
+        let (_, invalid_updates) = partition_updates(&self.rules, &self.updates);
+        return part_2(&self.rules, invalid_updates).into();
+    }
+}
+
+fn partition_updates<'a>(rules: &HashMap<i32, HashSet<i32>>, updates: &'a [Vec<i32>]) -> (Vec<&'a Vec<i32>>, Vec<Vec<i32>>) {
+    let mut valid_updates: Vec<&Vec<i32>> = vec![];
+    let mut invalid_updates: Vec<Vec<i32>> = vec![];
+    'update: for pages in updates {
+        let mut banned_pages: HashSet<i32> = HashSet::new();
+        for page in pages.iter() {
+            if banned_pages.contains(page) {
+                // ordering is invalid, stop processing this update but save this list for part 2
+                invalid_updates.push(pages.clone());
+                continue 'update;
+            }
+            if let Some(banned_page_set) = rules.get(page) {
+                // ordering valid so far, add new banned pages
+                banned_pages.extend(banned_page_set);
+            }
+        }
+        valid_updates.push(pages);
+    }
+    return (valid_updates, invalid_updates);
+}
+
+fn part_2(rules: &HashMap<i32, HashSet<i32>>, mut invalid_updates: Vec<Vec<i32>>) -> i32 {
+    let mut sum: i32 = 0;
+    for invalid_update in invalid_updates.iter_mut() {
+        // custom comparator to sort such that a number is greater than all numbers that appear in
+        // its banned set
+        // if a number does not appear in the rules map, it has irrelevant ordering
+        invalid_update.sort_by(|one, two| {
+            if rules.contains_key(one) && rules[one].contains(two) { Ordering::Greater }
+            else if rules.contains_key(two) && rules[two].contains(one) { Ordering::Less }
+            else { Ordering::Equal }
+        });
+        sum += invalid_update[invalid_update.len() / 2];
+    }
+    return sum;
+}
+
+// rules that order 49 pages, followed by updates of an odd number of pages
+pub fn generate(rng: &mut Rng, updates: usize) -> String {
+    // the order the rules describe, every pair of pages gets a rule like in the puzzle
+    let mut pages: Vec<i64> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+    let mut rules: Vec<String> = vec![];
+    for (index, before) in pages.iter().enumerate() {
+        for after in &pages[index + 1..] {
+            rules.push(format!("{}|{}", before, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut output = rules.join("\n") + "\n\n";
+    for _ in 0..updates {
+        let mut positions: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut positions);
+        positions.truncate(5 + 2 * rng.below(10));
+        // roughly half of the updates are already in the right order
+        if rng.chance(0.5) {
+            positions.sort();
+        }
+        output.push_str(&positions.iter().map(|it| pages[*it].to_string()).collect::<Vec<_>>().join(","));
+        output.push('\n');
+    }
+    return output;
+}
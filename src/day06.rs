@@ -0,0 +1,198 @@
+use std::collections::{HashMap, HashSet};
+use std::thread;
+use std::sync::mpsc;
+
+use util::random::Rng;
+use util::viz::{Animate, Frame};
+use util::{Answer, Direction, Grid, InputError, Point, Solution};
+
+pub struct Day06 {
+    grid: Grid<char>,
+    start: Point,
+}
+
+impl Solution for Day06 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let grid = util::try_parse_grid(input)?;
+        // find the starting point
+        let start = grid.find(&'^').map(Point::from).ok_or_else(|| InputError::missing("Expected the guard '^' somewhere on the map"))?;
+        return Ok(Day06 { grid, start });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&self.grid, self.start).len().into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let visited = part_1(&self.grid, self.start);
+        part_2(&self.grid, self.start, visited).into()
+    }
+}
+
+impl Animate for Day06 {
+    // a frame for every step of the guard's patrol, with the tiles covered so far
+    // a guard that never leaves ends on a frame showing the loop they're stuck in
+    fn frames(&self) -> Box<dyn Iterator<Item = Frame>> {
+        let mut grid = self.grid.clone();
+        grid[self.start] = '.';
+        let mut position = self.start;
+        let mut direction = Direction::Up;
+        let mut visited: HashSet<Point> = HashSet::new();
+        // every position and direction so far in order, and the step each one was first at
+        let mut path: Vec<Point> = vec![];
+        let mut steps: HashMap<(Point, Direction), usize> = HashMap::new();
+        let mut looped = false;
+        return Box::new(std::iter::from_fn(move || {
+            if !grid.contains(position) || looped { return None; }
+            if let Some(&step) = steps.get(&(position, direction)) {
+                looped = true;
+                let mut tiles = grid.clone();
+                tiles[position] = direction.to_char();
+                return Some(Frame::new(tiles)
+                    .with_visited(visited.iter().copied())
+                    .with_highlights(path[step..].iter().copied())
+                    .with_caption(format!("{} tiles visited, the guard is stuck in a loop", visited.len())));
+            }
+            steps.insert((position, direction), path.len());
+            path.push(position);
+            visited.insert(position);
+            // the guard is drawn facing the way they're walking
+            let mut tiles = grid.clone();
+            tiles[position] = direction.to_char();
+            let frame = Frame::new(tiles)
+                .with_visited(visited.iter().copied())
+                .with_highlights([position])
+                .with_caption(format!("{} tiles visited", visited.len()));
+            if grid.get(position + direction) == Some(&'#') {
+                direction = direction.turn_right();
+            }
+            else {
+                position += direction;
+            }
+            return Some(frame);
+        }));
+    }
+}
+
+fn part_1(grid: &Grid<char>, start: Point) -> HashSet<Point> {
+    let mut position = start;
+    let mut direction = Direction::Up;
+    let mut visited: HashSet<Point> = HashSet::new();
+
+    while grid.contains(position) {
+        visited.insert(position);
+        let next_position = position + direction;
+        if grid.get(next_position) == Some(&'#') {
+            // hit an obstacle, turn
+            direction = direction.turn_right();
+        }
+        else {
+            position = next_position;
+        }
+    }
+
+    return visited;
+}
+
+fn part_2(grid: &Grid<char>, start: Point, visited: HashSet<Point>) -> usize {
+    let mut sum = 0;
+    let thread_count = 100;
+    // the number of obstacles that each thread will tackle
+    let size = visited.len() / thread_count;
+    let mut to_check: Vec<Point> = visited.into_iter().collect();
+
+    // set up send and receive channel for message passing
+    let (tx, rx) = mpsc::channel();
+
+    for thread_id in 0..thread_count {
+        // clone these so ownership isn't moved into the thread
+        let thread_grid = grid.clone();
+        let thread_tx = tx.clone();
+
+        // remove a segment from the main list with length size (or shorter if  this is the last
+        // thread
+        let segment: Vec<Point> = if thread_id == thread_count - 1 {
+            to_check.clone()
+        }
+        else { 
+            to_check.drain(0..size).collect()
+        };
+
+        thread::spawn(move || {
+            thread_tx.send(check_obstacles(thread_grid, start, segment)).unwrap();
+        });
+    }
+
+    for _thread_id in 0..thread_count {
+        // recv is blocking so this will receive from any thread that has finished until they have
+        // all sent something back
+        // this means joining on join handles is unnecessary
+        sum += rx.recv().unwrap();
+    }
+
+    return sum;
+}
+
+fn check_obstacles(grid: Grid<char>, start: Point, visited: Vec<Point>) -> usize {
+    let mut sum = 0;
+
+    // replace each tile that was visited with an obstacle to see if it creates a cycle
+    // computer go brrr :)
+    for point in visited {
+        if grid[point] == '.' {
+            let mut new_grid = grid.clone();
+            new_grid[point] = '#';
+            if check_cycle(new_grid, start) {
+                sum += 1;
+            }
+        }
+    }
+
+    return sum;
+}
+
+fn check_cycle(grid: Grid<char>, start: Point) -> bool {
+    let mut position = start;
+    let mut direction = Direction::Up; 
+
+    // keep track of tiles we've visited and what direction we were moving
+    let mut visited: HashSet<(Point, Direction)> = HashSet::new();
+
+    while grid.contains(position) {
+        if !visited.insert((position, direction)) {
+            // we are on the same tile moving in the same direction as before
+            // will always result in a cycle
+            return true;
+        }
+        let next_position = position + direction;
+        if grid.get(next_position) == Some(&'#') {
+            // hit an obstacle, turn
+            direction = direction.turn_right();
+        }
+        else {
+            position = next_position;
+        }
+    }
+    
+    // we've exited the map, no cycle created
+    return false;
+}
+
+// a square lab with scattered obstructions and a guard that eventually walks off the map
+pub fn generate(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(2);
+    loop {
+        let mut grid = Grid::new(side, side, '.');
+        for position in grid.positions().collect::<Vec<_>>() {
+            if rng.chance(0.05) {
+                grid[position] = '#';
+            }
+        }
+        let start = (rng.below(side), rng.below(side));
+        grid[start] = '^';
+        // part 1 would never end if the guard walked in circles
+        if !check_cycle(grid.clone(), Point::from(start)) {
+            return grid.to_string();
+        }
+    }
+}
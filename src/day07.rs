@@ -0,0 +1,98 @@
+use itertools::Itertools;
+use std::iter;
+
+use util::random::Rng;
+use util::{Answer, InputError, Solution};
+
+pub struct Day07 {
+    equations: Vec<(usize, Vec<usize>)>,
+}
+
+impl Solution for Day07 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut equations: Vec<(usize, Vec<usize>)> = vec![];
+        for line in util::numbered_lines(input) {
+            let (expected, numbers) = line.split_once(line.text, ": ")?;
+            let expected: usize = line.parse(expected)?;
+            let numbers: Vec<usize> = numbers.split(" ").map(|number| line.parse::<usize>(number)).collect::<Result<_, _>>()?;
+            equations.push((expected, numbers));
+        }
+        return Ok(Day07 { equations });
+    }
+
+    fn part_1(&self) -> Answer {
+        calibrate(&self.equations, 1).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        calibrate(&self.equations, 2).into()
+    }
+}
+
+fn calibrate(equations: &[(usize, Vec<usize>)], part: usize) -> usize {
+    let mut sum = 0;
+    for (expected, numbers) in equations {
+        if valid_equation(*expected, numbers, part) { sum += expected; }
+    }
+    return sum;
+}
+
+fn valid_equation(expected: usize, numbers: &[usize], part: usize) -> bool {
+    let operators = if part == 1 { vec!['+', '*'] } else { vec!['+', '*', '|'] };
+
+    // get every permutation with replacement
+    // there is always one fewer operation than the number of numbers
+    let permutations = iter::repeat_n(operators.into_iter(), numbers.len() - 1).multi_cartesian_product();
+    for permutation in permutations {
+        let mut sum = numbers[0];
+        for (i, operator) in permutation.iter().enumerate() {
+            sum = match operator {
+                '+' => sum + numbers[i + 1],
+                '*' => sum * numbers[i + 1],
+                '|' => format!("{}{}", sum, numbers[i + 1]).parse().unwrap(),
+                _ => sum
+            };
+            if sum > expected {
+                // already too high, abandon this permutation
+                break;
+            }
+        }
+        if sum == expected { 
+            return true; 
+        } 
+    }
+    return false;
+}
+
+// equations of 2 to 12 numbers, about half of them can be made true
+pub fn generate(rng: &mut Rng, equations: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..equations {
+        let count = rng.range(2..=12) as usize;
+        // mostly single digits like the puzzle, with the odd bigger number
+        let numbers: Vec<usize> = (0..count).map(|_| {
+            let largest = if rng.chance(0.7) { 9 } else { 999 };
+            return rng.range(1..=largest) as usize;
+        }).collect();
+        // combine them with random operators, stopping before the total gets bigger than the puzzle's
+        let mut total = numbers[0];
+        let mut used = 1;
+        for number in &numbers[1..] {
+            let next = match rng.below(3) {
+                0 => total.checked_add(*number),
+                1 => total.checked_mul(*number),
+                _ => format!("{}{}", total, number).parse().ok(),
+            };
+            match next {
+                Some(next) if next < 100_000_000_000_000 => { total = next; used += 1; },
+                _ => break,
+            }
+        }
+        if rng.chance(0.5) {
+            total += rng.range(1..=100) as usize;
+        }
+        let numbers: Vec<String> = numbers[..used].iter().map(|it| it.to_string()).collect();
+        output.push_str(&format!("{}: {}\n", total, numbers.join(" ")));
+    }
+    return output;
+}
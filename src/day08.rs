@@ -0,0 +1,107 @@
+use std::collections::{HashMap, HashSet};
+
+use util::random::Rng;
+use util::{Answer, Grid, InputError, Point, Solution};
+
+pub struct Day08 {
+    antennas: HashMap<char, Vec<Point>>,
+    grid: Grid<char>,
+}
+
+impl Solution for Day08 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let grid = util::try_parse_grid(input)?;
+        // group the antennas by their frequency
+        let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+        for (position, character) in grid.iter() {
+            if *character == '.' { continue; }
+            antennas.entry(*character).or_default().push(Point::from(position));
+        }
+        return Ok(Day08 { antennas, grid });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&self.antennas, &self.grid).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(&self.antennas, &self.grid).into()
+    }
+}
+
+fn part_1(antennas: &HashMap<char, Vec<Point>>, grid: &Grid<char>) -> usize {
+    // use a HashSet for antinodes because we want unique number
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    // for every antenna type
+    for locations in antennas.values() {
+        for antenna_1 in 0..locations.len() - 1 {
+            for antenna_2 in antenna_1 + 1..locations.len() {
+                // for every unique combination of two antennas
+                let distance = locations[antenna_2] - locations[antenna_1];
+                // insert both antinodes in opposite directions
+                antinodes.insert(locations[antenna_1] - distance);
+                antinodes.insert(locations[antenna_2] + distance);
+            }
+        }
+    }
+
+    // make sure antinodes are in the map boundaries
+    return antinodes.into_iter().filter(|it| grid.contains(*it)).count();
+}
+
+fn part_2(antennas: &HashMap<char, Vec<Point>>, grid: &Grid<char>) -> usize {
+    // use a HashSet for antinodes because we want unique number
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    // for every antenna type
+    for locations in antennas.values() {
+        for antenna_1 in 0..locations.len() - 1 {
+            for antenna_2 in antenna_1 + 1..locations.len() {
+                // for every unique combination of two antennas
+                let distance = locations[antenna_2] - locations[antenna_1];
+
+                // insert antinodes going left until we run off the map
+                let mut multiplier: i64 = 0;
+                loop {
+                    let antinode = locations[antenna_1] - distance * multiplier; 
+                    if grid.contains(antinode) { 
+                        antinodes.insert(antinode); 
+                        multiplier += 1;
+                    } 
+                    else { 
+                        break; 
+                    }
+                } 
+                // insert antinodes going right until we run off the map
+                multiplier = 1;
+                loop {
+                    let antinode = locations[antenna_1] + distance * multiplier; 
+                    if grid.contains(antinode) { 
+                        antinodes.insert(antinode); 
+                        multiplier += 1;
+                    } 
+                    else { 
+                        break; 
+                    }
+               }
+            }
+        }
+    }
+    return antinodes.len();
+}
+
+// a square map with a handful of antennas for each frequency
+pub fn generate(rng: &mut Rng, side: usize) -> String {
+    const FREQUENCIES: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+    let mut grid = Grid::new(side, side, '.');
+    let mut empty: Vec<(usize, usize)> = grid.positions().collect();
+    rng.shuffle(&mut empty);
+    for frequency in FREQUENCIES.chars().take((side * side / 60).max(1)) {
+        for _ in 0..rng.range(3..=4) {
+            let Some(position) = empty.pop() else { break; };
+            grid[position] = frequency;
+        }
+    }
+    return grid.to_string();
+}
@@ -0,0 +1,123 @@
+use std::collections::BinaryHeap;
+
+use util::random::Rng;
+use util::{Answer, InputError, Solution};
+
+pub struct Day09 {
+    // each segment on the disk is (file id, length), free space uses an id of -1
+    disk: Vec<(i64, i64)>,
+    heap: BinaryHeap<(i64, i64)>,
+}
+
+impl Solution for Day09 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut disk: Vec<(i64, i64)> = vec![];
+        let mut heap: BinaryHeap<(i64, i64)> = BinaryHeap::new();
+
+        // file ids increase starting from 0
+        let mut id: i64 = 0;
+        let line = util::numbered_lines(input).next().ok_or_else(|| InputError::missing("Expected a disk map but the input is empty"))?;
+        for (index, character) in line.text.trim_end().char_indices() {
+            let length: i64 = match character.to_digit(10) {
+                Some(digit) => digit.into(),
+                None => { return Err(InputError::at(line.number, index + 1, format!("Expected a digit but found '{}'", character))); },
+            };
+            if index % 2 == 0 {
+                disk.push((id, length));
+                heap.push((id, length));
+                id += 1;
+            }
+            else {
+                disk.push((-1, length));
+            }
+        }
+        return Ok(Day09 { disk, heap });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(self.disk.clone(), self.heap.clone()).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(self.disk.clone(), self.heap.clone()).into()
+    }
+}
+
+fn part_1(mut disk: Vec<(i64, i64)>, mut heap: BinaryHeap<(i64, i64)>) -> i64 {
+    // keep going until there are no more files to move
+    while let Some((id, length)) = heap.pop() {
+        if let Some(free_space_index) = disk.iter().position(|it| it.0 == -1) {
+            let free_space = disk[free_space_index].1;
+            let remaining = free_space - length;
+            if remaining == 0 { 
+                // file matching a gap exactly, just move it and delete old file from end
+                disk.splice(free_space_index..free_space_index + 1, [(id, length)]); 
+                let position = disk.iter().rposition(|it| it.0 == id).unwrap();
+                disk.remove(position);
+            }
+            else if remaining > 0 { 
+                // more free space than the file size, move the file, add the remaining space,
+                // delete old file from end
+                disk.splice(free_space_index..free_space_index + 1, [(id, length), (-1, remaining)]); 
+                let position = disk.iter().rposition(|it| it.0 == id).unwrap();
+                disk.remove(position);
+            }
+            else { 
+                // not enough space for whole file, move the portion that will fit, update the file
+                // on disk and in the heap to have the new shorter length
+                disk.splice(free_space_index..free_space_index + 1, [(id, free_space)]); 
+                heap.push((id, length - free_space));
+                let position = disk.iter().rposition(|it| it.0 == id).unwrap();
+                disk[position] = (id, length - free_space);
+            }
+
+            // remove any trailing free space from the end of the disk
+            while disk[disk.len() - 1].0 == -1 {
+                disk.pop();
+            }
+        }
+        else { break; }
+    }
+
+    return checksum(&disk);
+}
+
+fn part_2(mut disk: Vec<(i64, i64)>, mut heap: BinaryHeap<(i64, i64)>) -> i64 {
+    while let Some((id, length)) = heap.pop() {
+        // find a gap that can accomodate this file
+        if let Some(index) = disk.iter().position(|it| it.0 == -1 && it.1 >= length) {
+           let space = disk[index].1;
+            // move the file and update and remaining space
+            disk.splice(index..index + 1, [(id, length), (-1, space - length)]);
+            let position = disk.iter().rposition(|it| it.0 == id).unwrap();
+            // remove the file from the end of disk
+            disk.splice(position..position + 1, [(-1, length)]);
+        }
+    }
+
+    return checksum(&disk);
+}
+
+fn checksum(disk: &[(i64, i64)]) -> i64 {
+    let mut checksum: i64 = 0;
+    let mut index: i64 = 0;
+    for segment in disk.iter() {
+        if segment.0 == -1 { index += segment.1; }
+        else {
+            for _ in 0..segment.1 {
+                checksum += segment.0 * index;
+                index += 1;
+            }
+        }
+    }
+    return checksum;
+}
+
+// a disk map of the given length, files take 1 to 9 blocks and the gaps between them 0 to 9
+pub fn generate(rng: &mut Rng, length: usize) -> String {
+    let map: String = (0..length.max(1)).map(|index| {
+        let blocks = if index % 2 == 0 { rng.range(1..=9) } else { rng.range(0..=9) };
+        return char::from_digit(blocks as u32, 10).unwrap();
+    }).collect();
+    return map + "\n";
+}
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use util::random::Rng;
+use util::viz::{Animate, Frame};
+use util::{Answer, Grid, InputError, Point, Solution};
+
+pub struct Day10 {
+    grid: Grid<i32>,
+    trailheads: Vec<(usize, usize)>,
+}
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let characters = util::try_parse_grid(input)?;
+        if let Some(((x, y), character)) = characters.iter().find(|(_, it)| !it.is_ascii_digit()) {
+            return Err(InputError::at(y + 1, x + 1, format!("Expected a height but found '{}'", character)));
+        }
+        // convert the grid to heights and keep track of 0's that are seen
+        let grid = characters.map(|it| it.to_digit(10).unwrap() as i32);
+        let trailheads: Vec<(usize, usize)> = grid.positions_of(&0).collect();
+        return Ok(Day10 { grid, trailheads });
+    }
+
+    fn part_1(&self) -> Answer {
+        process(&self.grid, &self.trailheads).0.into()
+    }
+
+    fn part_2(&self) -> Answer {
+        process(&self.grid, &self.trailheads).1.into()
+    }
+}
+
+impl Animate for Day10 {
+    // a frame for every trailhead, with the tiles on its hiking trails and the 9s they end on
+    fn frames(&self) -> Box<dyn Iterator<Item = Frame>> {
+        let grid = self.grid.clone();
+        let tiles = grid.map(|it| char::from_digit(*it as u32, 10).unwrap());
+        return Box::new(self.trailheads.clone().into_iter().map(move |trailhead| {
+            let mut trails: HashSet<(usize, usize)> = HashSet::new();
+            let mut ends: HashSet<(usize, usize)> = HashSet::new();
+            let rating = find_hikes(&grid, &mut ends, trailhead, 0);
+            find_trails(&grid, trailhead, 0, &mut trails);
+            return Frame::new(tiles.clone())
+                .with_path(trails)
+                .with_highlights(ends.iter().copied().chain([trailhead]))
+                .with_caption(format!("Trailhead {},{} has a score of {} and a rating of {}", trailhead.0, trailhead.1, ends.len(), rating));
+        }));
+    }
+}
+
+// adds every tile that is on a trail from here to a 9, returns whether there were any
+fn find_trails(grid: &Grid<i32>, location: (usize, usize), elevation: i32, trails: &mut HashSet<(usize, usize)>) -> bool {
+    let mut found = elevation == 9;
+    for point in grid.neighbors(location) {
+        if grid[point] == elevation + 1 && find_trails(grid, point, elevation + 1, trails) {
+            found = true;
+        }
+    }
+    if found {
+        trails.insert(location);
+    }
+    return found;
+}
+
+fn process(grid: &Grid<i32>, trailheads: &[(usize, usize)]) -> (usize, usize) {
+    let mut part_1 = 0;
+    let mut part_2 = 0;
+    for trailhead in trailheads {
+        let mut set: HashSet<(usize, usize)> = HashSet::new();
+        part_2 += find_hikes(grid, &mut set, *trailhead, 0); 
+        part_1 += set.len();
+    }
+    return (part_1, part_2);
+}
+
+fn find_hikes(grid: &Grid<i32>, set: &mut HashSet<(usize, usize)>, location: (usize, usize), elevation: i32) -> usize {
+    // set is used for part 1 to eliminate different paths to the same 9
+    if elevation == 9 {
+        set.insert(location);
+        return 1;
+    }
+    else {
+        let mut hikes = 0;
+        for point in grid.neighbors(location) {
+            if grid[point] == elevation + 1 {
+                hikes += find_hikes(grid, set, point, elevation + 1);
+            }
+        }
+        return hikes;
+    }
+}
+
+// a square height map of hills, every tile is as high as it is far from the nearest trailhead (wrapping
+// back to 0 past 9), with some tiles knocked out of place so not every route is a trail
+pub fn generate(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(1);
+    let trailheads: Vec<Point> = (0..(side * side / 100).max(1)).map(|_| Point::new(rng.below(side) as i64, rng.below(side) as i64)).collect();
+    let grid = Grid::new(side, side, '.');
+    let heights: Vec<char> = grid.positions().map(|position| {
+        if rng.chance(0.1) {
+            return char::from_digit(rng.below(10) as u32, 10).unwrap();
+        }
+        let distance = trailheads.iter().map(|it| it.manhattan(Point::from(position))).min().unwrap();
+        return char::from_digit((distance % 10) as u32, 10).unwrap();
+    }).collect();
+    return Grid::from_vec(side, side, heights).to_string();
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use util::random::Rng;
+use util::{Answer, InputError, Solution};
+
+pub struct Day11 {
+    // map from the number engraved on a stone -> how many stones have that number
+    stones: HashMap<usize, usize>,
+}
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut stones: HashMap<usize, usize> = HashMap::new();
+        for line in util::numbered_lines(input) {
+            for number in line.text.split_whitespace() {
+                *stones.entry(line.parse(number)?).or_insert(0) += 1;
+            }
+        }
+        return Ok(Day11 { stones });
+    }
+
+    fn part_1(&self) -> Answer {
+        process(self.stones.clone(), 25).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        process(self.stones.clone(), 75).into()
+    }
+}
+
+fn process(mut stones: HashMap<usize, usize>, blinks: usize) -> usize {
+    for _ in 0..blinks {
+        // make a new map each iteration to avoid dealing with repeats
+        let mut new_stones: HashMap<usize, usize> = HashMap::new();
+        for key in stones.keys() {
+            let results = blink(*key); 
+            for result in results {
+                // if there weren't any in the map yet, default to 0 so only the new ones are added
+                new_stones.insert(result, new_stones.get(&result).unwrap_or(&0) + stones[key]);
+            }
+        }
+        // update to use the new map for the next iteration
+        stones = new_stones;
+    }
+    return stones.values().sum();
+}
+
+fn blink(number: usize) -> Vec<usize> {
+    if number == 0 {
+        return vec![1];
+    }
+    // convert to string to find number of digits because I'm lazy
+    let digits = number.to_string();
+    if digits.len().is_multiple_of(2) {
+        return vec![digits[0..digits.len() / 2].parse().unwrap(), digits[digits.len() / 2..].parse().unwrap()];        
+    }
+    return vec![number * 2024];
+}
+
+// a line of stones like the puzzle's, with a few zeros and numbers of every length up to 7 digits
+pub fn generate(rng: &mut Rng, stones: usize) -> String {
+    let numbers: Vec<String> = (0..stones).map(|_| {
+        if rng.chance(0.1) {
+            return "0".to_owned();
+        }
+        let digits = rng.range(1..=7) as u32;
+        return rng.range(10i64.pow(digits - 1)..=10i64.pow(digits) - 1).to_string();
+    }).collect();
+    return numbers.join(" ") + "\n";
+}
@@ -0,0 +1,163 @@
+use std::collections::{HashMap, HashSet};
+
+use util::random::Rng;
+use util::viz::{Animate, Frame};
+use util::{Answer, Grid, InputError, Point, Solution};
+
+pub struct Day12 {
+    grid: Grid<char>,
+}
+
+impl Solution for Day12 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        return Ok(Day12 { grid: util::try_parse_grid(input)? });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&process_regions(&self.grid)).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(&process_regions(&self.grid)).into()
+    }
+}
+
+impl Animate for Day12 {
+    // a frame for every region in the order they're found, with the ones before it shaded
+    fn frames(&self) -> Box<dyn Iterator<Item = Frame>> {
+        let grid = self.grid.clone();
+        let mut done: HashSet<(usize, usize)> = HashSet::new();
+        let mut positions = grid.positions();
+        return Box::new(std::iter::from_fn(move || {
+            let start = positions.by_ref().find(|it| !done.contains(it))?;
+            let letter = grid[start];
+            let mut region: HashSet<(usize, usize)> = HashSet::new();
+            flood_fill(letter, start, &grid, &mut region);
+            let (area, perimeter, sides) = (region.len(), calculate_perimeter(&grid, &region), calculate_sides(letter, &grid, &region));
+            let frame = Frame::new(grid.clone())
+                .with_visited(done.iter().copied())
+                .with_highlights(region.iter().copied())
+                .with_caption(format!("Region {} at {},{}: area {}, perimeter {}, {} sides", letter, start.0, start.1, area, perimeter, sides));
+            done.extend(region);
+            return Some(frame);
+        }));
+    }
+}
+
+fn part_1(regions: &HashMap<(usize, usize), (usize, usize, usize)>) -> usize {
+    // calculate cost with area * perimeter for each region
+    let mut cost = 0;
+    for region in regions.values() {
+        cost += region.0 * region.1;
+    }
+    return cost;
+}
+
+fn part_2(regions: &HashMap<(usize, usize), (usize, usize, usize)>) -> usize {
+    // calculate cost with area * sides for each region 
+    let mut cost = 0;
+    for region in regions.values() {
+        cost += region.0 * region.2;
+    }
+    return cost;
+}
+
+fn process_regions(grid: &Grid<char>) -> HashMap<(usize, usize), (usize, usize, usize)> {
+    // this keeps track of every tile visited so we know where to start the next flood fill
+    let mut master_visited: HashSet<(usize, usize)> = HashSet::new();
+    // map for different regions: start tile -> (area, perimeter, sides)
+    let mut regions: HashMap<(usize, usize), (usize, usize, usize)> = HashMap::new();
+
+    for (position, letter) in grid.iter() {
+        // we've seen this tile, move on
+        if master_visited.contains(&position) { continue; } 
+
+        let mut visited: HashSet<(usize, usize)> = HashSet::new();
+        // start a flood fill for this plot
+        flood_fill(*letter, position, grid, &mut visited);
+        let area = visited.len();
+        let perimeter = calculate_perimeter(grid, &visited);
+        let sides = calculate_sides(*letter, grid, &visited);
+
+        // add to the map if this is plot type that's already been seen, otherwise add it
+        regions.insert(position, (area, perimeter, sides));
+
+        // finally, update master_visited to include the tiles from this flood fill
+        master_visited.extend(visited);
+    }
+    return regions;
+}
+
+fn flood_fill(
+    letter: char, 
+    start: (usize, usize),
+    grid: &Grid<char>, 
+    visited: &mut HashSet<(usize, usize)>,
+) {
+    // make sure we don't ever recount this tile
+    visited.insert(start);
+    let new_points: Vec<(usize, usize)> = grid.neighbors(start)
+        // points are only new if we haven't visited them yet and they have the right value
+        .filter(|coords| !visited.contains(coords) && grid[*coords] == letter).collect();
+
+    for point in new_points {
+        // recursive call for each new point to visit
+        flood_fill(letter, point, grid, visited);
+    } 
+}
+
+fn calculate_perimeter(grid: &Grid<char>, visited: &HashSet<(usize, usize)>) -> usize {
+    let mut perimeter = 0;
+    for point in visited {
+        // contributing perimeter for this tile is 4 - number of adjacent tiles in the region
+        perimeter += 4 - grid.neighbors(*point).filter(|point| visited.contains(point)).count();
+    }
+    return perimeter;
+}
+
+fn calculate_sides(letter: char, grid: &Grid<char>, visited: &HashSet<(usize, usize)>) -> usize {
+    // number of sides is actually number of corners, so look for that
+    let mut sides = 0;
+    for point in visited {
+        // use a nonsense character for anything out of bounds
+        let letters: Vec<char> = get_sides_points(*point).into_iter().map(|it| *grid.get(it).unwrap_or(&'!')).collect();
+
+        // if 3 in a row vertical or horizontal then no corners at all
+        if letters[0] == letter && letters[4] == letter && letters[2] != letter && letters[6] != letter { continue; }
+        if letters[2] == letter && letters[6] == letter && letters[0] != letter && letters[4] != letter { continue; }
+
+        // check interior corners
+        // upper left, upper right, lower left, lower right
+        if letters[0] == letter && letters[6] == letter && letters[7] != letter { sides += 1; }
+        if letters[0] == letter && letters[2] == letter && letters[1] != letter { sides += 1; }
+        if letters[4] == letter && letters[6] == letter && letters[5] != letter { sides += 1; }
+        if letters[4] == letter && letters[2] == letter && letters[3] != letter { sides += 1; }
+
+        // check exterior corners
+        // upper left, upper right, lower left, lower right
+        if letters[0] != letter && letters[6] != letter { sides += 1; }
+        if letters[0] != letter && letters[2] != letter { sides += 1; }
+        if letters[4] != letter && letters[6] != letter { sides += 1; }
+        if letters[4] != letter && letters[2] != letter { sides += 1; }
+    }
+    return sides;
+}
+
+fn get_sides_points(point: (usize, usize)) -> [Point; 8] {
+    // get points going [north, northeast, east, southeast, south, southwest, west, northwest]
+    let offsets = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+    return offsets.map(|(x, y)| Point::from(point) + Point::new(x, y));
+}
+
+// a square garden of irregular regions, made by letting random plots claim whatever is closest
+pub fn generate(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(1);
+    let seeds: Vec<(Point, char)> = (0..(side * side / 25).max(1))
+        .map(|_| (Point::new(rng.below(side) as i64, rng.below(side) as i64), (b'A' + rng.below(26) as u8) as char))
+        .collect();
+    let grid = Grid::new(side, side, '.');
+    let garden = Grid::from_vec(side, side, grid.positions().map(|position| {
+        return seeds.iter().min_by_key(|(seed, _)| seed.manhattan(Point::from(position))).unwrap().1;
+    }).collect());
+    return garden.to_string();
+}
@@ -0,0 +1,119 @@
+use util::random::Rng;
+use util::{Answer, InputError, Line, Solution};
+
+#[derive(Clone)]
+struct Machine {
+    a_x: i64,
+    a_y: i64,
+    b_x: i64,
+    b_y: i64,
+    prize_x: i64,
+    prize_y: i64,
+}
+
+pub struct Day13 {
+    machines: Vec<Machine>,
+}
+
+impl Solution for Day13 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        // blank lines after the last machine are left off, files often end with one
+        let mut lines = util::numbered_lines(input.trim_end());
+        let mut machines: Vec<Machine> = vec![];
+
+        loop {
+            let a = extract_values(lines.next(), "Button A")?;
+            let b = extract_values(lines.next(), "Button B")?;
+            let prize = extract_values(lines.next(), "Prize")?;
+            machines.push(Machine { a_x: a.0, a_y: a.1, b_x: b.0, b_y: b.1, prize_x: prize.0, prize_y: prize.1 });
+            // if there's no blank line, this was the last entry
+            if lines.next().is_none() { break; }
+        }
+        return Ok(Day13 { machines });
+    }
+
+    fn part_1(&self) -> Answer {
+        // each button is pressed at most 100 times
+        compute_tokens(&self.machines, Some(100)).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        // the prizes are actually much further away than originally measured
+        let machines: Vec<Machine> = self.machines.iter().map(|machine| Machine {
+            prize_x: machine.prize_x + 10000000000000,
+            prize_y: machine.prize_y + 10000000000000,
+            ..machine.clone()
+        }).collect();
+        compute_tokens(&machines, None).into()
+    }
+}
+
+fn compute_tokens(machines: &[Machine], limit: Option<i64>) -> i64 {
+    let mut tokens = 0;
+    
+    for machine in machines {
+        // use Cramer's rule to solve a system of equations with 2 equations and 2 unknowns
+        // find value for a
+        let a: f64 = (machine.prize_x * machine.b_y - machine.prize_y * machine.b_x) as f64 / (machine.a_x * machine.b_y - machine.a_y * machine.b_x) as f64;
+        // use a to find b by plugging into first equation
+        let b: f64 = (machine.prize_x as f64 - a * machine.a_x as f64) / machine.b_x as f64;
+        if a.fract() != 0.0 || b.fract() != 0.0 { 
+            // not a whole number, there is no way to win on this machine
+            continue; 
+        }
+        // buttons can't be pressed a negative number of times either
+        if a < 0.0 || b < 0.0 || limit.is_some_and(|it| a > it as f64 || b > it as f64) {
+            continue;
+        }
+        // 3 tokens to push a, 1 token for b 
+        tokens += a as i64 * 3 + b as i64;
+    }
+
+    return tokens;
+}
+
+fn extract_values(line: Option<Line>, label: &str) -> Result<(i64, i64), InputError> {
+    let line = line.ok_or_else(|| InputError::missing(format!("Expected a '{}' line but the input ended", label)))?;
+    let (name, right_side) = line.split_once(line.text, ": ")?;
+    if name != label {
+        return Err(line.error(name, format!("Expected '{}' but found '{}'", label, name)));
+    }
+    let (x_string, y_string) = line.split_once(right_side, ", ")?;
+    // buttons use X+94 while prizes use X=8400
+    let symbol = if label == "Prize" { "=" } else { "+" };
+    let x: i64 = line.parse(line.strip_prefix(x_string, &format!("X{}", symbol))?)?;
+    let y: i64 = line.parse(line.strip_prefix(y_string, &format!("Y{}", symbol))?)?;
+    return Ok((x, y));
+}
+
+// machines like the puzzle's, about a third of them can't be won
+pub fn generate(rng: &mut Rng, machines: usize) -> String {
+    let mut blocks: Vec<String> = vec![];
+    for _ in 0..machines {
+        // buttons that move in the same direction give infinitely many or no ways to win, the puzzle never has them
+        let (a, b) = loop {
+            let a = (rng.range(10..=99), rng.range(10..=99));
+            let b = (rng.range(10..=99), rng.range(10..=99));
+            if a.0 * b.1 != a.1 * b.0 { break (a, b); }
+        };
+        let prize = if rng.chance(0.66) {
+            let presses = (rng.range(0..=100), rng.range(0..=100));
+            (a.0 * presses.0 + b.0 * presses.1, a.1 * presses.0 + b.1 * presses.1)
+        }
+        else {
+            // the puzzle's other machines can't be won with any whole number of presses, rather than
+            // needing a negative number or more than 100 of them
+            loop {
+                let prize = (rng.range(1000..=20000), rng.range(1000..=20000));
+                let determinant = a.0 * b.1 - a.1 * b.0;
+                if (prize.0 * b.1 - prize.1 * b.0) % determinant != 0 || (a.0 * prize.1 - a.1 * prize.0) % determinant != 0 {
+                    break prize;
+                }
+            }
+        };
+        blocks.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n", a.0, a.1, b.0, b.1, prize.0, prize.1,
+        ));
+    }
+    return blocks.join("\n");
+}
@@ -0,0 +1,169 @@
+use util::random::Rng;
+use util::viz::{Animate, Frame};
+use util::{Answer, Grid, InputError, Solution};
+
+#[derive(Clone)]
+struct Robot {
+    position: (i32, i32),
+    velocity: (i32, i32),
+    // where the robot was in the input, for saying which one doesn't fit a smaller bathroom
+    line: usize,
+}
+
+// size of the bathroom in the real puzzle, the example is smaller
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+pub struct Day14 {
+    robots: Vec<Robot>,
+    size: (i32, i32),
+}
+
+impl Day14 {
+    // every robot has to start inside the new bathroom as well
+    pub fn with_size(self, width: i32, height: i32) -> Result<Self, InputError> {
+        for robot in &self.robots {
+            if !(0..width).contains(&robot.position.0) || !(0..height).contains(&robot.position.1) {
+                return Err(InputError::on_line(robot.line, format!(
+                    "Expected a position inside the {}x{} bathroom but found {},{}", width, height, robot.position.0, robot.position.1,
+                )));
+            }
+        }
+        return Ok(Day14 { size: (width, height), ..self });
+    }
+}
+
+impl Solution for Day14 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut robots: Vec<Robot> = vec![];
+        for line in util::numbered_lines(input) {
+            // create each robot
+            let (left_side, right_side) = line.split_once(line.text, " ")?;
+            let (x_position_string, y_position_string) = line.split_once(line.strip_prefix(left_side, "p=")?, ",")?;
+            let (x_velocity_string, y_velocity_string) = line.split_once(line.strip_prefix(right_side, "v=")?, ",")?;
+            let x_position: i32 = line.parse(x_position_string)?;
+            let y_position: i32 = line.parse(y_position_string)?;
+            let x_velocity: i32 = line.parse(x_velocity_string)?;
+            let y_velocity: i32 = line.parse(y_velocity_string)?;
+            if !(0..WIDTH).contains(&x_position) {
+                return Err(line.error(x_position_string, format!("Expected an x position from 0 to {} but found {}", WIDTH - 1, x_position)));
+            }
+            if !(0..HEIGHT).contains(&y_position) {
+                return Err(line.error(y_position_string, format!("Expected a y position from 0 to {} but found {}", HEIGHT - 1, y_position)));
+            }
+            robots.push(Robot { position: (x_position, y_position), velocity: (x_velocity, y_velocity), line: line.number });
+        }
+        return Ok(Day14 { robots, size: (WIDTH, HEIGHT) });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&mut self.robots.clone(), 100, self.size).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(&mut self.robots.clone(), self.size).into()
+    }
+}
+
+impl Animate for Day14 {
+    // a frame for every second until the robots form the tree part 2 looks for
+    fn frames(&self) -> Box<dyn Iterator<Item = Frame>> {
+        let tree = part_2(&mut self.robots.clone(), self.size);
+        let mut robots = self.robots.clone();
+        let size = self.size;
+        return Box::new((0..=tree).map(move |second| {
+            if second > 0 {
+                robots.iter_mut().for_each(|robot| move_robot(robot, size));
+            }
+            // tiles show how many robots are on them, the same way the puzzle draws them
+            let mut grid = Grid::new(size.0 as usize, size.1 as usize, '.');
+            for robot in &robots {
+                let tile = &mut grid[(robot.position.0 as usize, robot.position.1 as usize)];
+                *tile = match *tile {
+                    '.' => '1',
+                    count => char::from_digit(count.to_digit(10).unwrap_or(9) + 1, 10).unwrap_or('+'),
+                };
+            }
+            return Frame::new(grid)
+                .with_caption(format!("{} seconds, safety factor {}", second, calculate_safety(&robots, size)));
+        }));
+    }
+}
+
+fn part_1(robots: &mut [Robot], seconds: usize, size: (i32, i32)) -> usize {
+    // move each robot for the given number of seconds 
+    for _ in 0..seconds {
+        for robot in robots.iter_mut() {
+            move_robot(robot, size);
+        }
+    }
+
+    return calculate_safety(robots, size);
+}
+
+fn part_2(robots: &mut [Robot], size: (i32, i32)) -> usize {
+    // look through every second and see when the safety factor is the lowest
+    // the rationale behind this is that to form the christmas tree most robots must be together in
+    // the same quadrant, therefore lowering the safety factor
+    let seconds = 10000;
+    let mut lowest: usize = usize::MAX;
+    let mut second_seen = 0;
+
+    // start from 1 second since the first move happens on 1 not 0
+    for second in 1..seconds {
+        for robot in robots.iter_mut() {
+            move_robot(robot, size);
+        }
+        let safety = calculate_safety(robots, size);
+        if safety < lowest {
+            lowest = safety;
+            second_seen = second;
+        }
+    }
+
+    return second_seen;
+}
+
+fn move_robot(robot: &mut Robot, (width, height): (i32, i32)) {
+    robot.position.0 += robot.velocity.0;
+    robot.position.1 += robot.velocity.1;
+
+    // ensure that robots wrap around to other side of grid, even moving further than its size in a
+    // smaller bathroom
+    robot.position.0 = robot.position.0.rem_euclid(width);
+    robot.position.1 = robot.position.1.rem_euclid(height);
+}
+
+fn calculate_safety(robots: &[Robot], (width, height): (i32, i32)) -> usize {
+    let mut quadrants: [usize; 4] = [0, 0, 0, 0];
+    let x_midline: i32 = height / 2;
+    let y_midline: i32 = width / 2;
+    for robot in robots.iter() {
+        // left side
+        if robot.position.0 < y_midline {
+            if robot.position.1 < x_midline {
+                quadrants[0] += 1;
+            }
+            else if robot.position.1 > x_midline {
+                quadrants[2] += 1;
+            }
+        } 
+        // right side
+        else if robot.position.0 > y_midline {
+            if robot.position.1 < x_midline {
+                quadrants[1] += 1;
+            }
+            else if robot.position.1 > x_midline {
+                quadrants[3] += 1;
+            }
+        }
+    }
+    return quadrants[0] * quadrants[1] * quadrants[2] * quadrants[3];
+}
+
+// robots anywhere in the real bathroom moving at up to 99 tiles a second
+pub fn generate(rng: &mut Rng, robots: usize) -> String {
+    return (0..robots).map(|_| format!(
+        "p={},{} v={},{}\n", rng.range(0..=WIDTH as i64 - 1), rng.range(0..=HEIGHT as i64 - 1), rng.range(-99..=99), rng.range(-99..=99),
+    )).collect();
+}
@@ -0,0 +1,189 @@
+use util::random::Rng;
+use util::viz::{Animate, Frame};
+use util::{Answer, Direction, Grid, InputError, Point, Solution};
+
+pub struct Day15 {
+    grid: Grid<char>,
+    grid_2: Grid<char>,
+    start: Point,
+    start_2: Point,
+    moves: Vec<Direction>,
+}
+
+impl Solution for Day15 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut lines = util::numbered_lines(input);
+
+        // read in the grid section first
+        let mut rows: Vec<Vec<char>> = vec![];
+        let mut rows_2: Vec<Vec<char>> = vec![];
+        loop {
+            let line = lines.next().ok_or_else(|| InputError::missing("Expected a blank line between the warehouse and the moves"))?;
+            if line.text.is_empty() { break; }
+
+            rows.push(line.text.chars().collect());
+            // for part 2, double width of everything
+            rows_2.push(line.text.chars().flat_map(|character| match character {
+                '@' => ['@', '.'],
+                'O' => ['[', ']'],
+                _ => [character, character],
+            }).collect());
+        } 
+        let grid = Grid::from_rows(rows).ok_or_else(|| InputError::missing("Expected every row of the warehouse to be the same width"))?;
+        let grid_2 = Grid::from_rows(rows_2).unwrap();
+
+        // save the guard's starting position
+        let start = grid.find(&'@').map(Point::from).ok_or_else(|| InputError::missing("Expected the robot '@' somewhere in the warehouse"))?;
+        let start_2 = Point::new(start.x * 2, start.y);
+
+        // read in the moves
+        let mut moves: Vec<Direction> = vec![];
+        for line in lines {
+            for (column, character) in line.text.chars().enumerate() {
+                let direction = Direction::try_from(character)
+                    .map_err(|it| InputError::at(line.number, column + 1, format!("Expected a move but found '{}'", it)))?;
+                moves.push(direction);
+            }
+        }
+        return Ok(Day15 { grid, grid_2, start, start_2, moves });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(self.start, &mut self.grid.clone(), &self.moves).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(self.start_2, &mut self.grid_2.clone(), &self.moves).into()
+    }
+}
+
+impl Animate for Day15 {
+    // a frame for every move of the robot around the wide warehouse from part 2
+    fn frames(&self) -> Box<dyn Iterator<Item = Frame>> {
+        let mut grid = self.grid_2.clone();
+        let mut position = self.start_2;
+        let moves = self.moves.clone();
+        return Box::new((0..=moves.len()).map(move |index| {
+            // the first frame is the warehouse before the robot does anything
+            if index > 0 && check_space(position, moves[index - 1], &grid) {
+                position = move_stack(position, moves[index - 1], &mut grid);
+            }
+            let caption = match moves.get(index) {
+                Some(next) => format!("Move {} of {}: {}", index + 1, moves.len(), next),
+                None => format!("Done after {} moves", moves.len()),
+            };
+            return Frame::new(grid.clone())
+                .with_highlights([position])
+                .with_caption(caption);
+        }));
+    }
+}
+
+fn part_1(start: Point, grid: &mut Grid<char>, moves: &[Direction]) -> usize {
+    process(start, grid, moves); 
+    
+    // calculate the sum of box GPS coords
+    let mut sum = 0;
+    for (position, value) in grid.iter() {
+        if *value == 'O' {
+           sum += 100 * position.1 + position.0; 
+        }
+    }
+    return sum;
+}
+
+fn part_2(start: Point, grid: &mut Grid<char>, moves: &[Direction]) -> usize {
+    process(start, grid, moves); 
+    
+    // calculate the sum of box GPS coords
+    let mut sum = 0;
+    for (position, value) in grid.iter() {
+        if *value == '[' {
+            sum += 100 * position.1 + position.0; 
+        }
+    }
+    return sum;
+}
+
+fn process(start: Point, grid: &mut Grid<char>, moves: &[Direction]) {
+    let mut position = start;
+    for direction in moves {
+        if check_space(position, *direction, grid) {
+            position = move_stack(position, *direction, grid);
+        }
+    }
+}
+
+fn check_space(position: Point, direction: Direction, grid: &Grid<char>) -> bool {
+    let mut current_position = position;
+    // traverse in the given direction until we find a blank space or hit edge
+    loop {
+        current_position += direction;
+        match grid.get(current_position) {
+            // found space
+            Some(&'.') => { return true; },
+            // wall, no room to move
+            Some(&'#') => { return false; },
+            // left side of a box, need to check for the right side if moving up or down
+            Some(&'[') => {
+                if direction.is_vertical() && !check_space(current_position + Direction::Right, direction, grid) {
+                    return false;
+                }
+            }
+            // right side of a box, need to check for the right side if moving up or down
+            Some(&']') => {
+                if direction.is_vertical() && !check_space(current_position + Direction::Left, direction, grid) {
+                    return false;
+                }
+            }
+            // keep checking
+            Some(_) => {},
+            // went of the grid, no space found
+            None => { return false; }
+        }
+    }
+}
+
+fn move_stack(position: Point, direction: Direction, grid: &mut Grid<char>) -> Point {
+    let mut current_position = position;
+    // store the next tile into a temp so we don't overwrite it
+    let mut temporary = grid[current_position];
+    // the character to fill the next tile with
+    let mut to_fill = '.';
+    loop {
+        grid[current_position] = to_fill;
+        if temporary == '.' {
+            // we've reached the space that was needed for this move, stop updating tiles
+            break;
+        }
+        else if current_position != position && temporary == '[' && direction.is_vertical() {
+            // also need to move the right side
+            move_stack(current_position + Direction::Right, direction, grid);
+        }
+        else if current_position != position && temporary == ']' && direction.is_vertical() {
+            // also need to move the left side
+            move_stack(current_position + Direction::Left, direction, grid);
+        }
+        to_fill = temporary;
+        current_position += direction;
+        temporary = grid[current_position];
+    }
+    // return the new position of the guard
+    return position + direction;
+}
+
+// a square walled warehouse full of boxes, with a long list of moves for the robot
+pub fn generate(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(3);
+    let mut grid = Grid::new(side, side, '#');
+    for (x, y) in grid.positions().collect::<Vec<_>>() {
+        if x == 0 || y == 0 || x == side - 1 || y == side - 1 { continue; }
+        grid[(x, y)] = if rng.chance(0.05) { '#' } else if rng.chance(0.25) { 'O' } else { '.' };
+    }
+    grid[(side / 2, side / 2)] = '@';
+
+    // 20000 moves for the puzzle's 50x50 warehouse, in lines of 1000
+    let moves: Vec<char> = (0..8 * side * side).map(|_| rng.pick(&Direction::ALL).to_char()).collect();
+    let moves: Vec<String> = moves.chunks(1000).map(|it| it.iter().collect()).collect();
+    return format!("{}\n\n{}\n", grid, moves.join("\n"));
+}
@@ -0,0 +1,109 @@
+use std::collections::{HashMap, HashSet};
+
+use util::random::{self, Rng};
+use util::search::{self, Search};
+use util::viz::{Animate, Frame};
+use util::{Answer, Direction, Grid, InputError, Point, Solution};
+
+pub struct Day16 {
+    grid: Grid<char>,
+    start: Point,
+}
+
+impl Solution for Day16 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let grid = util::try_parse_grid(input)?;
+        // the reindeer starts on S and we need an E to be able to finish
+        let start = grid.find(&'S').map(Point::from).ok_or_else(|| InputError::missing("Expected a start tile 'S' in the maze"))?;
+        grid.find(&'E').ok_or_else(|| InputError::missing("Expected an end tile 'E' in the maze"))?;
+        return Ok(Day16 { grid, start });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&find_paths(&self.grid, self.start)).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(&find_paths(&self.grid, self.start)).into()
+    }
+}
+
+impl Animate for Day16 {
+    // the search spreading out a thousand points (one turn) at a time, then the best seats
+    fn frames(&self) -> Box<dyn Iterator<Item = Frame>> {
+        let paths = find_paths(&self.grid, self.start);
+        let Some(best) = paths.goal_distance() else {
+            return Box::new([Frame::new(self.grid.clone()).with_caption("There's no way to the end")].into_iter());
+        };
+        // the lowest score each tile was reached with, whichever way the reindeer was facing
+        let mut scores: HashMap<Point, usize> = HashMap::new();
+        for ((position, _), score) in &paths.distances {
+            let lowest = scores.entry(*position).or_insert(*score);
+            *lowest = (*lowest).min(*score);
+        }
+        let mut seats: HashSet<Point> = HashSet::new();
+        for goal in &paths.goals {
+            seats.extend(paths.on_best_paths(goal).into_iter().map(|(position, _)| position));
+        }
+
+        let ends: Vec<Point> = self.grid.positions_of(&'S').chain(self.grid.positions_of(&'E')).map(Point::from).collect();
+        let last = Frame::new(self.grid.clone())
+            .with_path(seats.iter().copied())
+            .with_highlights(ends)
+            .with_caption(format!("{} seats on the best paths, which score {}", seats.len(), best));
+        let grid = self.grid.clone();
+        let spreading = (0..=best / 1000).map(move |turns| {
+            let reached = scores.iter().filter(|(_, score)| **score <= turns * 1000).map(|(position, _)| *position);
+            return Frame::new(grid.clone())
+                .with_visited(reached.collect::<Vec<_>>())
+                .with_caption(format!("Tiles reached with a score of {} or less", turns * 1000));
+        });
+        return Box::new(spreading.chain([last]));
+    }
+}
+
+fn part_1(paths: &Search<(Point, Direction)>) -> usize {
+    // lowest score to reach the end, 0 if there is no way through
+    return paths.goal_distance().unwrap_or(0);
+}
+
+fn part_2(paths: &Search<(Point, Direction)>) -> usize {
+    // the end can be reached facing different directions, any of them with the lowest score counts
+    let mut seats: HashSet<Point> = HashSet::new();
+    for goal in &paths.goals {
+        seats.extend(paths.on_best_paths(goal).into_iter().map(|(position, _)| position));
+    }
+    return seats.len();
+}
+
+fn find_paths(grid: &Grid<char>, start: Point) -> Search<(Point, Direction)> {
+    // the reindeer starts facing east, the direction is part of the state since turning costs extra
+    let successors = |(position, facing): &(Point, Direction)| {
+        let mut next: Vec<((Point, Direction), usize)> = vec![];
+        for direction in Direction::ALL {
+            let point = *position + direction;
+            // can't walk into walls
+            if grid.get(point).is_none_or(|it| *it == '#') { continue; }
+            // add 1 for forward steps add 1000 for turns
+            next.push(((point, direction), if *facing == direction { 1 } else { 1001 }));
+        }
+        next
+    };
+    return search::dijkstra([(start, Direction::Right)], successors, |(position, _)| grid[*position] == 'E');
+}
+
+// a square maze with the start in the bottom left, the end in the top right and some loops
+pub fn generate(rng: &mut Rng, side: usize) -> String {
+    let mut grid = random::maze(rng, side, side);
+    // a perfect maze has only one way through, knock out some walls so there are ties to find
+    for (x, y) in grid.positions().collect::<Vec<_>>() {
+        if x == 0 || y == 0 || x == grid.width() - 1 || y == grid.height() - 1 { continue; }
+        if grid[(x, y)] == '#' && rng.chance(0.1) {
+            grid[(x, y)] = '.';
+        }
+    }
+    let (width, height) = (grid.width(), grid.height());
+    grid[(1, height - 2)] = 'S';
+    grid[(width - 2, 1)] = 'E';
+    return grid.to_string();
+}
@@ -0,0 +1,231 @@
+use std::fmt;
+
+use util::random::Rng;
+use util::{Answer, InputError, Line, Solution};
+
+pub mod asm;
+pub mod debugger;
+pub mod vm;
+
+use vm::{Fault, Registers, Stop, Vm};
+
+/* my program input simplifies to
+*   b <- a % 8
+*   b <- b ^ 1
+*   c <- a / 2^b
+*   a <- a / 8
+*   b <- b ^ c
+*   output b % 8
+*   jump 0 if a != 0
+* day17 --disasm prints the same for any program
+*/
+
+pub struct Day17 {
+    a: i64,
+    b: i64,
+    c: i64,
+    instructions: Vec<i64>,
+    // what part 2 looks for register a to output, the program itself unless changed
+    target: Option<Vec<i64>>,
+}
+
+impl Solution for Day17 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut lines = util::numbered_lines(input);
+        // populate registers
+        let a = parse_register(lines.next(), "A")?; 
+        let b = parse_register(lines.next(), "B")?; 
+        let c = parse_register(lines.next(), "C")?; 
+        // consume the blank line
+        lines.next();
+        // populate the list of instructions
+        let line = lines.next().ok_or_else(|| InputError::missing("Expected a 'Program' line but the input ended"))?;
+        let instructions = parse_program(&line)?;
+        return Ok(Day17 { a, b, c, instructions, target: None });
+    }
+
+    // a program the machine can't run has no answer, so the reason why is given instead
+    fn part_1(&self) -> Answer {
+        return match part_1(Registers { a: self.a, b: self.b, c: self.c }, &self.instructions) {
+            Ok(output) => asm::format_program(&output).into(),
+            Err(fault) => Answer::Unsolved(fault.to_string()),
+        };
+    }
+
+    fn part_2(&self) -> Answer {
+        let target = self.target.as_ref().unwrap_or(&self.instructions);
+        return match search(&self.instructions, target) {
+            Ok(a) => a.into(),
+            Err(why) => Answer::Unsolved(why.to_string()),
+        };
+    }
+}
+
+impl Day17 {
+    // lets part 2 search for a value of a that outputs something other than the program
+    pub fn with_target(mut self, target: Vec<i64>) -> Self {
+        self.target = Some(target);
+        return self;
+    }
+
+    // a machine loaded with the puzzle's program and registers
+    pub fn vm(&self) -> Vm<'_> {
+        return Vm::new(&self.instructions, Registers { a: self.a, b: self.b, c: self.c });
+    }
+}
+
+// everything the program outputs, or why the machine couldn't finish running it
+fn part_1(registers: Registers, instructions: &[i64]) -> Result<Vec<i64>, Fault> {
+    let mut vm = Vm::new(instructions, registers);
+    if let Stop::Fault(fault) = vm.run() {
+        return Err(fault);
+    }
+    return Ok(vm.output().to_vec());
+}
+
+// why part 2 has no answer for a program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchError {
+    // the search only knows programs shaped like the puzzle's, see analyse
+    Unsupported(String),
+    // no value of a outputs the target
+    NoSolution,
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            SearchError::Unsupported(why) => write!(f, "Can't search this program: {}", why),
+            SearchError::NoSolution => write!(f, "No value of register A outputs the target"),
+        };
+    }
+}
+
+// the lowest value of a that makes the program output the target
+// each time around the loop a is shifted right by the same number of bits and one value is output
+// that only depends on what's left of a, so a can be built up from its top bits down, trying every
+// value of the next few bits against the output they would have to give and backtracking on a dead end
+pub fn search(instructions: &[i64], target: &[i64]) -> Result<i64, SearchError> {
+    let shift = analyse(instructions)?;
+    if target.is_empty() {
+        // the loop always runs once, so there's always some output
+        return Err(SearchError::NoSolution);
+    }
+    let found = extend(instructions, target, shift, target.len() - 1, 0).ok_or(SearchError::NoSolution)?;
+    // analyse should have ruled out anything this could go wrong for, but the machine has the last word
+    if part_1(Registers { a: found, b: 0, c: 0 }, instructions).as_deref() != Ok(target) {
+        return Err(SearchError::Unsupported(format!("A={} should output the target but doesn't", found)));
+    }
+    return Ok(found);
+}
+
+// finds the lowest a that outputs target[..=index] when its bits above the shift are prefix
+fn extend(instructions: &[i64], target: &[i64], shift: i64, index: usize, prefix: i64) -> Option<i64> {
+    for bits in 0..1 << shift {
+        // past 63 bits there's nowhere left for a to go
+        let a = prefix.checked_mul(1 << shift)? + bits;
+        // the loop only goes round again while a isn't 0
+        if a == 0 && index > 0 {
+            continue;
+        }
+        let output = Vm::new(instructions, Registers { a, b: 0, c: 0 }).run_until_output();
+        if output != Ok(target[index]) {
+            continue;
+        }
+        if index == 0 {
+            return Some(a);
+        }
+        if let Some(found) = extend(instructions, target, shift, index - 1, a) {
+            return Some(found);
+        }
+    }
+    return None;
+}
+
+// checks the program is a loop the search can handle and returns how far a is shifted each time round,
+// which means:
+//   jnz 0 is the last instruction and the only jump
+//   there's one out in the loop
+//   a only changes by one adv with a literal operand
+//   b and c are set before they're read, so each output only depends on a
+fn analyse(instructions: &[i64]) -> Result<i64, SearchError> {
+    let unsupported = |why: &str| SearchError::Unsupported(why.to_owned());
+    let valid = instructions.len().is_multiple_of(2) && instructions.chunks(2)
+        .all(|pair| (0..8).contains(&pair[0]) && (0..8).contains(&pair[1]) && !(pair[1] == 7 && vm::takes_combo(pair[0])));
+    if !valid {
+        return Err(unsupported("it isn't a valid program"));
+    }
+    let pairs: Vec<(i64, i64)> = instructions.chunks(2).map(|pair| (pair[0], pair[1])).collect();
+    if pairs.last() != Some(&(3, 0)) || pairs.iter().filter(|(opcode, _)| *opcode == 3).count() != 1 {
+        return Err(unsupported("expected a single loop, with jnz 0 as the last instruction and no other jumps"));
+    }
+    if pairs.iter().filter(|(opcode, _)| *opcode == 5).count() != 1 {
+        return Err(unsupported("expected one out in the loop"));
+    }
+    let shifts: Vec<i64> = pairs.iter().filter(|(opcode, _)| *opcode == 0).map(|(_, operand)| *operand).collect();
+    let shift = match shifts[..] {
+        [shift @ 1..=3] => shift,
+        _ => return Err(unsupported("expected a to only change by one adv 1, 2 or 3")),
+    };
+
+    // whether b and c have been set yet
+    let (mut b, mut c) = (false, false);
+    for (opcode, operand) in pairs {
+        let combo = vm::takes_combo(opcode);
+        let reads_b = opcode == 1 || opcode == 4 || (combo && operand == 5);
+        let reads_c = opcode == 4 || (combo && operand == 6);
+        if (reads_b && !b) || (reads_c && !c) {
+            return Err(unsupported("expected b and c to be set from a before they're read"));
+        }
+        b |= matches!(opcode, 2 | 6);
+        c |= opcode == 7;
+    }
+    return Ok(shift);
+}
+
+fn parse_register(line: Option<Line>, name: &str) -> Result<i64, InputError> {
+    let line = line.ok_or_else(|| InputError::missing(format!("Expected register {} but the input ended", name)))?;
+    let register = line.strip_prefix(line.text, &format!("Register {}: ", name))?;
+    return line.parse(register);
+}
+
+// a line like "Program: 0,1,5,4,3,0"
+fn parse_program(line: &Line) -> Result<Vec<i64>, InputError> {
+    let program = line.strip_prefix(line.text, "Program: ")?;
+    let mut instructions: Vec<i64> = vec![];
+    for token in program.split(",") {
+        let value: i64 = line.parse(token)?;
+        // everything in the program is a 3-bit number
+        if !(0..8).contains(&value) {
+            return Err(line.error(token, format!("Expected a 3-bit number but found {}", value)));
+        }
+        instructions.push(value);
+    }
+    // every opcode needs an operand after it
+    if !instructions.len().is_multiple_of(2) {
+        return Err(InputError::on_line(line.number, "Expected the program to be pairs of opcodes and operands"));
+    }
+    return Ok(instructions);
+}
+
+// a program with the same shape as the puzzle's (see the top of the file) with random constants and
+// the middle instructions in a random order, a is picked so it outputs the given number of values
+pub fn generate(rng: &mut Rng, outputs: usize) -> String {
+    let outputs = outputs.clamp(1, 20) as u32;
+    let a = rng.range(8i64.pow(outputs - 1)..=8i64.pow(outputs) - 1);
+    // only a few programs of this shape have a value of a that outputs the program itself, so keep
+    // picking until part 2's search finds one
+    let instructions = loop {
+        let mut middle = vec![[0, 3], [1, rng.range(0..=7)], [4, rng.range(0..=7)]];
+        rng.shuffle(&mut middle);
+        let instructions: Vec<i64> = [[2, 4], [1, rng.range(0..=7)], [7, 5]].into_iter()
+            .chain(middle)
+            .chain([[5, 5], [3, 0]])
+            .flatten()
+            .collect();
+        if search(&instructions, &instructions).is_ok() {
+            break instructions;
+        }
+    };
+    return format!("Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", a, asm::format_program(&instructions));
+}
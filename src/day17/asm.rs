@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use util::{InputError, Line};
+
+use super::vm::{takes_combo, MNEMONICS};
+
+// the program from an input, either a whole puzzle input or just its "Program: ..." line
+pub fn find_program(input: &str) -> Result<Vec<i64>, InputError> {
+    let line = util::numbered_lines(input).find(|it| it.text.starts_with("Program:"))
+        .ok_or_else(|| InputError::missing("Expected a 'Program' line but there wasn't one"))?;
+    return super::parse_program(&line);
+}
+
+// a combo operand as it's written, the registers by name
+pub fn combo_name(operand: i64) -> String {
+    return match operand {
+        4 => "A".to_owned(),
+        5 => "B".to_owned(),
+        6 => "C".to_owned(),
+        _ => operand.to_string(),
+    };
+}
+
+// the label for a jump target, named after its address
+fn label(address: i64) -> String {
+    return format!("l{}", address);
+}
+
+// one instruction per line with what it does in a comment after it, jump targets get a label
+// l0:
+//     bst A       ; 0: B = A % 8
+//     jnz l0      ; 2: jump to l0 if A != 0
+pub fn disassemble(program: &[i64]) -> String {
+    // only targets that are the start of an instruction can be labeled, a jump anywhere else is
+    // left as a number
+    let targets: Vec<i64> = program.chunks(2)
+        .filter(|pair| pair[0] == 3 && pair.len() == 2 && pair[1] % 2 == 0 && (pair[1] as usize) < program.len())
+        .map(|pair| pair[1])
+        .collect();
+
+    let mut output = String::new();
+    for (index, pair) in program.chunks(2).enumerate() {
+        let address = index * 2;
+        if targets.contains(&(address as i64)) {
+            output.push_str(&format!("{}:\n", label(address as i64)));
+        }
+        let opcode = pair[0];
+        let Some(&operand) = pair.get(1) else {
+            output.push_str(&format!("    ; {}: opcode {} without an operand\n", address, opcode));
+            continue;
+        };
+        if !(0..8).contains(&opcode) || !(0..8).contains(&operand) {
+            output.push_str(&format!("    ; {}: {},{} isn't an instruction\n", address, opcode, operand));
+            continue;
+        }
+        let (instruction, comment) = describe(opcode, operand, targets.contains(&operand));
+        output.push_str(&format!("    {:<11} ; {}: {}\n", instruction, address, comment));
+    }
+    return output;
+}
+
+// one instruction as assembly, jump targets as plain addresses
+pub fn format_instruction(opcode: i64, operand: i64) -> String {
+    if !(0..8).contains(&opcode) || !(0..8).contains(&operand) {
+        return format!("{},{}", opcode, operand);
+    }
+    return describe(opcode, operand, false).0;
+}
+
+// the instruction as assembly and what it does
+fn describe(opcode: i64, operand: i64, labeled: bool) -> (String, String) {
+    let mnemonic = MNEMONICS[opcode as usize];
+    if takes_combo(opcode) && operand == 7 {
+        return (format!("{} 7", mnemonic), "combo operand 7 is reserved".to_owned());
+    }
+    let combo = combo_name(operand);
+    return match opcode {
+        0 => (format!("adv {}", combo), format!("A = A / 2^{}", combo)),
+        1 => (format!("bxl {}", operand), format!("B = B ^ {}", operand)),
+        2 => (format!("bst {}", combo), format!("B = {} % 8", combo)),
+        3 => {
+            let target = if labeled { label(operand) } else { operand.to_string() };
+            (format!("jnz {}", target), format!("jump to {} if A != 0", target))
+        },
+        // the operand is ignored, but only left out when it's 0 so the program assembles back the same
+        4 if operand == 0 => ("bxc".to_owned(), "B = B ^ C".to_owned()),
+        4 => (format!("bxc {}", operand), "B = B ^ C".to_owned()),
+        5 => (format!("out {}", combo), format!("output {} % 8", combo)),
+        6 => (format!("bdv {}", combo), format!("B = A / 2^{}", combo)),
+        _ => (format!("cdv {}", combo), format!("C = A / 2^{}", combo)),
+    };
+}
+
+// turns assembly like disassemble writes back into a program, labels are only needed by jnz and
+// everything after a ';' is a comment
+//     adv 3       ; operands are 0-3 or a register for adv, bst, out, bdv and cdv
+//     out A
+//     jnz start   ; a label or an address from 0 to 7
+pub fn assemble(source: &str) -> Result<Vec<i64>, InputError> {
+    // labels can be used before they're defined, so their addresses are found first
+    let mut labels: HashMap<&str, i64> = HashMap::new();
+    let mut instructions: Vec<(Line, &str, Option<&str>)> = vec![];
+    for line in util::numbered_lines(source) {
+        let mut code = line.text.split(';').next().unwrap_or_default().trim();
+        if let Some((name, rest)) = code.split_once(':') {
+            let name = name.trim();
+            if name.is_empty() || !name.chars().all(|it| it.is_ascii_alphanumeric() || it == '_') || name.starts_with(|it: char| it.is_ascii_digit()) {
+                return Err(line.error(name, format!("Invalid label '{}'", name)));
+            }
+            if labels.insert(name, instructions.len() as i64 * 2).is_some() {
+                return Err(line.error(name, format!("Label '{}' is defined twice", name)));
+            }
+            code = rest.trim();
+        }
+        let mut tokens = code.split_whitespace();
+        let Some(mnemonic) = tokens.next() else { continue; };
+        let operand = tokens.next();
+        if let Some(extra) = tokens.next() {
+            return Err(line.error(extra, format!("Unexpected '{}' after the operand", extra)));
+        }
+        instructions.push((line, mnemonic, operand));
+    }
+
+    let mut program: Vec<i64> = vec![];
+    for (line, mnemonic, operand) in instructions {
+        let Some(opcode) = MNEMONICS.iter().position(|it| *it == mnemonic) else {
+            return Err(line.error(mnemonic, format!("Unknown instruction '{}'", mnemonic)));
+        };
+        let opcode = opcode as i64;
+        let operand = match (opcode, operand) {
+            // bxc ignores its operand, so it's optional
+            (4, None) => 0,
+            (_, None) => return Err(line.error(mnemonic, format!("Expected an operand after '{}'", mnemonic))),
+            (3, Some(target)) => match labels.get(target) {
+                Some(address) if *address < 8 => *address,
+                Some(address) => return Err(line.error(target, format!("Label '{}' is at {}, but jnz can only jump to 0-7", target, address))),
+                None if target.starts_with(|it: char| it.is_ascii_digit()) => literal(&line, target)?,
+                None => return Err(line.error(target, format!("Unknown label '{}'", target))),
+            },
+            (_, Some(operand)) if takes_combo(opcode) => combo(&line, operand)?,
+            (_, Some(operand)) => literal(&line, operand)?,
+        };
+        program.extend([opcode, operand]);
+    }
+    return Ok(program);
+}
+
+// the comma separated form the puzzle input uses
+pub fn format_program(program: &[i64]) -> String {
+    return program.iter().map(|it| it.to_string()).collect::<Vec<_>>().join(",");
+}
+
+fn literal(line: &Line, token: &str) -> Result<i64, InputError> {
+    let value: i64 = line.parse(token)?;
+    if !(0..8).contains(&value) {
+        return Err(line.error(token, format!("Expected an operand from 0 to 7 but found {}", value)));
+    }
+    return Ok(value);
+}
+
+// registers are written by name, 4-6 as numbers would only be confusing
+fn combo(line: &Line, token: &str) -> Result<i64, InputError> {
+    return match token {
+        "A" => Ok(4),
+        "B" => Ok(5),
+        "C" => Ok(6),
+        "0" | "1" | "2" | "3" => line.parse(token),
+        "7" => Err(line.error(token, "Combo operand 7 is reserved")),
+        _ => Err(line.error(token, format!("Expected a combo operand (0-3, A, B or C) but found '{}'", token))),
+    };
+}
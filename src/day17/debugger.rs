@@ -0,0 +1,234 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::io::{self, BufRead, Write};
+
+use super::asm;
+use super::vm::{Stop, Vm};
+
+// earlier states kept to step back through, the oldest are forgotten after that
+pub const HISTORY_LIMIT: usize = 100_000;
+
+const PROMPT: &str = "(day17) ";
+
+const HELP: &str = "\
+step [count]          run the next instruction, or count of them (s)
+continue              run until a breakpoint, a watched output or the end (c)
+back [count]          undo the last instruction, or count of them (b)
+break <address>       stop before running the instruction at address
+delete <address>      remove a breakpoint
+watch [value]         stop after any output, or only after outputting value
+unwatch               stop watching the output
+set <a|b|c> <value>   change a register
+registers             show the registers and the next instruction (r)
+output                show everything output so far (o)
+list                  show the program, where it's up to and its breakpoints (l)
+quit                  stop debugging (q)
+";
+
+// what continue stops after
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Watch {
+    Off,
+    Any,
+    Value(i64),
+}
+
+// runs a machine a command at a time, remembering every state it was in so it can go back
+pub struct Debugger<'a> {
+    vm: Vm<'a>,
+    breakpoints: BTreeSet<usize>,
+    watch: Watch,
+    // the machine before each instruction and register change, newest last
+    history: VecDeque<Vm<'a>>,
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(vm: Vm<'a>) -> Self {
+        return Debugger { vm, breakpoints: BTreeSet::new(), watch: Watch::Off, history: VecDeque::new() };
+    }
+
+    pub fn vm(&self) -> &Vm<'a> {
+        return &self.vm;
+    }
+
+    // reads commands until quit or the end of the input, prompting for each one
+    pub fn run(&mut self, input: impl BufRead, output: &mut impl Write) -> io::Result<()> {
+        writeln!(output, "{}", self.status())?;
+        write!(output, "{}", PROMPT)?;
+        output.flush()?;
+        for line in input.lines() {
+            if !self.command(&line?, output)? {
+                return Ok(());
+            }
+            write!(output, "{}", PROMPT)?;
+            output.flush()?;
+        }
+        // the end of the input leaves the prompt without a newline after it
+        writeln!(output)?;
+        return Ok(());
+    }
+
+    // runs one command and returns false if it was quit
+    pub fn command(&mut self, line: &str, output: &mut impl Write) -> io::Result<bool> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        // step and back take an optional count
+        let count = words.get(1).map(|it| it.parse::<usize>().ok()).unwrap_or(Some(1));
+        match (words.as_slice(), count) {
+            ([], _) => {},
+            (["step" | "s"] | ["step" | "s", _], Some(count)) => self.step(count, output)?,
+            (["continue" | "c"], _) => self.resume(output)?,
+            (["back" | "b"] | ["back" | "b", _], Some(count)) => self.back(count, output)?,
+            (["break", address], _) => match self.address(address) {
+                Some(address) => {
+                    self.breakpoints.insert(address);
+                    writeln!(output, "Breakpoint at {}", address)?;
+                },
+                None => writeln!(output, "There's no instruction at {}", address)?,
+            },
+            (["delete", address], _) => match address.parse::<usize>() {
+                Ok(address) if self.breakpoints.remove(&address) => writeln!(output, "Removed the breakpoint at {}", address)?,
+                _ => writeln!(output, "There's no breakpoint at {}", address)?,
+            },
+            (["watch"], _) => {
+                self.watch = Watch::Any;
+                writeln!(output, "Watching for any output")?;
+            },
+            (["watch", value], _) => match value.parse() {
+                Ok(value) => {
+                    self.watch = Watch::Value(value);
+                    writeln!(output, "Watching for {} to be output", value)?;
+                },
+                Err(_) => writeln!(output, "Expected a number to watch for but found '{}'", value)?,
+            },
+            (["unwatch"], _) => {
+                self.watch = Watch::Off;
+                writeln!(output, "Not watching the output")?;
+            },
+            (["set", register, value], _) => self.set(register, value, output)?,
+            (["registers" | "r"], _) => writeln!(output, "{}", self.status())?,
+            (["output" | "o"], _) => writeln!(output, "{}", asm::format_program(self.vm.output()))?,
+            (["list" | "l"], _) => write!(output, "{}", self.listing())?,
+            (["help" | "h"], _) => write!(output, "{}", HELP)?,
+            (["quit" | "q"], _) => return Ok(false),
+            _ => writeln!(output, "Unknown command '{}', try help", line.trim())?,
+        }
+        return Ok(true);
+    }
+
+    // runs count instructions, or fewer if the machine stops first
+    fn step(&mut self, count: usize, output: &mut impl Write) -> io::Result<()> {
+        for _ in 0..count {
+            match self.advance() {
+                Ok(Some(value)) => writeln!(output, "Output {}", value)?,
+                Ok(None) => {},
+                Err(stop) => {
+                    writeln!(output, "{}", stop)?;
+                    break;
+                },
+            }
+        }
+        return writeln!(output, "{}", self.status());
+    }
+
+    fn resume(&mut self, output: &mut impl Write) -> io::Result<()> {
+        loop {
+            match self.advance() {
+                Ok(value) => {
+                    if let Some(value) = value {
+                        writeln!(output, "Output {}", value)?;
+                        if self.watch == Watch::Any || self.watch == Watch::Value(value) {
+                            break;
+                        }
+                    }
+                    if self.breakpoints.contains(&self.vm.pointer()) {
+                        writeln!(output, "Breakpoint at {}", self.vm.pointer())?;
+                        break;
+                    }
+                },
+                Err(stop) => {
+                    writeln!(output, "{}", stop)?;
+                    break;
+                },
+            }
+        }
+        return writeln!(output, "{}", self.status());
+    }
+
+    fn back(&mut self, count: usize, output: &mut impl Write) -> io::Result<()> {
+        for _ in 0..count {
+            let Some(vm) = self.history.pop_back() else {
+                writeln!(output, "Already back at the start")?;
+                break;
+            };
+            self.vm = vm;
+        }
+        return writeln!(output, "{}", self.status());
+    }
+
+    fn set(&mut self, register: &str, value: &str, output: &mut impl Write) -> io::Result<()> {
+        let Ok(value) = value.parse::<i64>() else {
+            return writeln!(output, "Expected a number for the register but found '{}'", value);
+        };
+        self.remember();
+        let registers = self.vm.registers_mut();
+        match register {
+            "a" | "A" => registers.a = value,
+            "b" | "B" => registers.b = value,
+            "c" | "C" => registers.c = value,
+            _ => {
+                self.history.pop_back();
+                return writeln!(output, "Expected register a, b or c but found '{}'", register);
+            },
+        }
+        return writeln!(output, "{}", self.status());
+    }
+
+    // steps the machine, keeping the state before it unless it didn't go anywhere
+    fn advance(&mut self) -> Result<Option<i64>, Stop> {
+        self.remember();
+        let result = self.vm.step();
+        if result.is_err() {
+            self.history.pop_back();
+        }
+        return result;
+    }
+
+    fn remember(&mut self) {
+        self.history.push_back(self.vm.clone());
+        if self.history.len() > HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+    }
+
+    // where an instruction starts, anything else can never be stopped at
+    fn address(&self, text: &str) -> Option<usize> {
+        return text.parse().ok().filter(|it: &usize| it.is_multiple_of(2) && *it < self.vm.program().len());
+    }
+
+    // the next instruction and the registers
+    //   4: cdv B       | A=729 B=1 C=0
+    fn status(&self) -> String {
+        let program = self.vm.program();
+        let pointer = self.vm.pointer();
+        let instruction = match (program.get(pointer), program.get(pointer + 1)) {
+            (Some(opcode), Some(operand)) => asm::format_instruction(*opcode, *operand),
+            _ => "end".to_owned(),
+        };
+        return format!("{:>3}: {:<11} | {}", pointer, instruction, self.vm.registers());
+    }
+
+    // every instruction with a > for the next one and a * for breakpoints
+    fn listing(&self) -> String {
+        let mut listing = String::new();
+        for (index, pair) in self.vm.program().chunks(2).enumerate() {
+            let address = index * 2;
+            let current = if address == self.vm.pointer() { '>' } else { ' ' };
+            let breakpoint = if self.breakpoints.contains(&address) { '*' } else { ' ' };
+            let instruction = match pair {
+                [opcode, operand] => asm::format_instruction(*opcode, *operand),
+                _ => pair[0].to_string(),
+            };
+            listing.push_str(&format!("{}{} {:>3}: {}\n", current, breakpoint, address, instruction));
+        }
+        return listing;
+    }
+}
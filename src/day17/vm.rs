@@ -0,0 +1,279 @@
+use std::fmt;
+
+// instructions run before a machine gives up, a program that never halts would otherwise hang
+pub const DEFAULT_LIMIT: usize = 1_000_000;
+
+// names of the instructions, indexed by opcode
+pub const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Registers {
+    pub a: i64,
+    pub b: i64,
+    pub c: i64,
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "A={} B={} C={}", self.a, self.b, self.c);
+    }
+}
+
+// whether an opcode's operand is a combo operand (0-3, then the registers) rather than a literal
+pub fn takes_combo(opcode: i64) -> bool {
+    return matches!(opcode, 0 | 2 | 5 | 6 | 7);
+}
+
+// something the program did that the machine can't carry out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    // only 0-7 are opcodes
+    UnknownOpcode { pointer: usize, opcode: i64 },
+    // combo operand 7 is reserved, and no operand can be more than 7
+    InvalidOperand { pointer: usize, operand: i64 },
+    // the last number of an odd length program is an opcode with nothing after it
+    MissingOperand { pointer: usize },
+    // a division by 2 to the power of a negative register
+    NegativeShift { pointer: usize, power: i64 },
+    // the machine ran as many instructions as it was allowed to
+    LimitReached { limit: usize },
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Fault::UnknownOpcode { pointer, opcode } => write!(f, "Unknown opcode {} at {}", opcode, pointer),
+            Fault::InvalidOperand { pointer, operand } => write!(f, "Invalid operand {} at {}", operand, pointer),
+            Fault::MissingOperand { pointer } => write!(f, "Missing an operand for the opcode at {}", pointer),
+            Fault::NegativeShift { pointer, power } => write!(f, "Division by 2^{} at {}", power, pointer),
+            Fault::LimitReached { limit } => write!(f, "Stopped after {} instructions", limit),
+        };
+    }
+}
+
+// why a machine stopped running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    // the instruction pointer moved past the end of the program
+    Halt,
+    Fault(Fault),
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Stop::Halt => write!(f, "Halted"),
+            Stop::Fault(fault) => write!(f, "{}", fault),
+        };
+    }
+}
+
+// one instruction the machine ran, with the registers either side of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub pointer: usize,
+    pub opcode: i64,
+    pub operand: i64,
+    pub before: Registers,
+    pub after: Registers,
+    pub output: Option<i64>,
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:>3}: {} {} | {}", self.pointer, MNEMONICS[self.opcode as usize], self.operand, self.after)?;
+        if let Some(value) = self.output {
+            write!(f, " | out {}", value)?;
+        }
+        return Ok(());
+    }
+}
+
+// the 3-bit computer, running a program one instruction at a time
+#[derive(Debug, Clone)]
+pub struct Vm<'a> {
+    program: &'a [i64],
+    registers: Registers,
+    pointer: i64,
+    output: Vec<i64>,
+    executed: usize,
+    // None to run for as long as the program does
+    limit: Option<usize>,
+    // only recorded when asked for, it grows with every instruction
+    trace: Option<Vec<TraceEntry>>,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(program: &'a [i64], registers: Registers) -> Self {
+        return Vm { program, registers, pointer: 0, output: vec![], executed: 0, limit: Some(DEFAULT_LIMIT), trace: None };
+    }
+
+    pub fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        return self;
+    }
+
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(vec![]);
+        return self;
+    }
+
+    pub fn program(&self) -> &'a [i64] {
+        return self.program;
+    }
+
+    pub fn registers(&self) -> Registers {
+        return self.registers;
+    }
+
+    pub fn registers_mut(&mut self) -> &mut Registers {
+        return &mut self.registers;
+    }
+
+    pub fn pointer(&self) -> usize {
+        return self.pointer as usize;
+    }
+
+    pub fn output(&self) -> &[i64] {
+        return &self.output;
+    }
+
+    // instructions run so far
+    pub fn executed(&self) -> usize {
+        return self.executed;
+    }
+
+    // empty unless the machine was made with_trace
+    pub fn trace(&self) -> &[TraceEntry] {
+        return self.trace.as_deref().unwrap_or_default();
+    }
+
+    // runs one instruction and returns what it output, if anything
+    // once the machine has stopped every step returns the same reason again
+    pub fn step(&mut self) -> Result<Option<i64>, Stop> {
+        let pointer = self.pointer as usize;
+        let Some(&opcode) = self.program.get(pointer) else { return Err(Stop::Halt); };
+        let Some(&operand) = self.program.get(pointer + 1) else { return Err(Stop::Fault(Fault::MissingOperand { pointer })); };
+        // everything the handlers would panic on is caught here first
+        if !(0..8).contains(&opcode) {
+            return Err(Stop::Fault(Fault::UnknownOpcode { pointer, opcode }));
+        }
+        if !(0..8).contains(&operand) || (operand == 7 && takes_combo(opcode)) {
+            return Err(Stop::Fault(Fault::InvalidOperand { pointer, operand }));
+        }
+        if matches!(opcode, 0 | 6 | 7) && get_combo_operand(&self.registers, operand) < 0 {
+            return Err(Stop::Fault(Fault::NegativeShift { pointer, power: get_combo_operand(&self.registers, operand) }));
+        }
+        if let Some(limit) = self.limit.filter(|it| self.executed >= *it) {
+            return Err(Stop::Fault(Fault::LimitReached { limit }));
+        }
+
+        let before = self.registers;
+        let registers = &mut self.registers;
+        let instruction_pointer = &mut self.pointer;
+        let mut output: Option<i64> = None;
+        match opcode {
+            0 => adv(registers, operand, instruction_pointer),
+            1 => bxl(registers, operand, instruction_pointer),
+            2 => bst(registers, operand, instruction_pointer),
+            3 => jnz(registers, operand, instruction_pointer),
+            4 => bxc(registers, operand, instruction_pointer),
+            5 => output = Some(out(registers, operand, instruction_pointer)),
+            6 => bdv(registers, operand, instruction_pointer),
+            _ => cdv(registers, operand, instruction_pointer),
+        }
+        self.executed += 1;
+        self.output.extend(output);
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceEntry { pointer, opcode, operand, before, after: self.registers, output });
+        }
+        return Ok(output);
+    }
+
+    // runs until the program outputs something and returns it
+    pub fn run_until_output(&mut self) -> Result<i64, Stop> {
+        loop {
+            if let Some(value) = self.step()? {
+                return Ok(value);
+            }
+        }
+    }
+
+    // runs until the program stops, everything it output is in output()
+    pub fn run(&mut self) -> Stop {
+        loop {
+            if let Err(stop) = self.step() {
+                return stop;
+            }
+        }
+    }
+}
+
+fn adv(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
+    // performs division of a and combo operand and writes into a
+    registers.a = divide(registers.a, get_combo_operand(registers, operand));
+    *instruction_pointer += 2;
+}
+
+fn bxl(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
+    // performs bitwise xor of b and operand and writes into b
+    registers.b ^= operand;
+    *instruction_pointer += 2;
+}
+
+fn bst(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
+    // calculates combo operand mod 8 and writes into b
+    registers.b = get_combo_operand(registers, operand) % 8;
+    *instruction_pointer += 2;
+}
+
+fn jnz(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
+    // do nothing if a is 0, otherwise jump to literal operand
+    if registers.a != 0 {
+        *instruction_pointer = operand;
+    }
+    else {
+        *instruction_pointer += 2;
+    }
+}
+
+fn bxc(registers: &mut Registers, _operand: i64, instruction_pointer: &mut i64) {
+    // performs bitwise xor of b and c and writes into c
+    // ignores the operand
+    registers.b ^= registers.c;
+    *instruction_pointer += 2;
+}
+
+fn out(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) -> i64 {
+    // calculates combo operand mod 8 and outputs it
+    *instruction_pointer += 2;
+    return get_combo_operand(registers, operand) % 8;
+}
+
+fn bdv(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
+    // adv but store into b
+    registers.b = divide(registers.a, get_combo_operand(registers, operand));
+    *instruction_pointer += 2;
+}
+
+fn cdv(registers: &mut Registers, operand: i64, instruction_pointer: &mut i64) {
+    // adv but store into c
+    registers.c = divide(registers.a, get_combo_operand(registers, operand));
+    *instruction_pointer += 2;
+}
+
+// numerator / 2^power, where 2^63 and up don't fit but would leave nothing of the numerator anyway
+fn divide(numerator: i64, power: i64) -> i64 {
+    return if power >= 63 { 0 } else { numerator / (1 << power) };
+}
+
+fn get_combo_operand(registers: &Registers, operand: i64) -> i64 {
+    return match operand {
+        // 0-3 are literal values
+        0..=3 => operand,
+        4 => registers.a,
+        5 => registers.b,
+        6 => registers.c,
+        _ => panic!("Unknown combo operator")
+    }
+}
@@ -0,0 +1,143 @@
+use util::random::Rng;
+use util::search::{self, Search};
+use util::viz::{Animate, Frame};
+use util::{Answer, Grid, InputError, Point, Solution};
+
+// the real memory space is 71x71 and part 1 looks at the first kilobyte, the example is smaller
+const SIZE: usize = 71;
+const FALLEN: usize = 1024;
+
+pub struct Day18 {
+    corrupted_bytes: Vec<(usize, usize)>,
+    size: usize,
+    fallen: usize,
+}
+
+impl Day18 {
+    // the memory space is square, so one size covers both sides, bytes that fall outside a smaller
+    // space than the real one are left out
+    pub fn with_size(self, size: usize) -> Self {
+        return Day18 { size, ..self };
+    }
+
+    // number of bytes that have fallen before part 1 looks for a path
+    pub fn with_fallen(self, fallen: usize) -> Self {
+        return Day18 { fallen, ..self };
+    }
+}
+
+impl Solution for Day18 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut corrupted_bytes: Vec<(usize, usize)> = vec![];
+        for line in util::numbered_lines(input) {
+            let (x, y) = line.split_once(line.text, ",")?;
+            let byte = (line.parse::<usize>(x)?, line.parse::<usize>(y)?);
+            for (token, value) in [(x, byte.0), (y, byte.1)] {
+                if value >= SIZE {
+                    return Err(line.error(token, format!("Expected a coordinate from 0 to {} but found {}", SIZE - 1, value)));
+                }
+            }
+            corrupted_bytes.push(byte);
+        }
+        return Ok(Day18 { corrupted_bytes, size: SIZE, fallen: FALLEN });
+    }
+
+    fn part_1(&self) -> Answer {
+        return match part_1(&self.corrupted_bytes[0..self.fallen.min(self.corrupted_bytes.len())], self.size) {
+            Some(steps) => steps.into(),
+            None => Answer::Unsolved("The exit can't be reached".to_owned()),
+        };
+    }
+
+    fn part_2(&self) -> Answer {
+        return match part_2(&self.corrupted_bytes, self.size) {
+            Some(byte) => byte.into(),
+            None => Answer::Unsolved("The exit is never cut off".to_owned()),
+        };
+    }
+}
+
+impl Animate for Day18 {
+    // a frame for every byte that falls, with a shortest way out until there isn't one any more
+    fn frames(&self) -> Box<dyn Iterator<Item = Frame>> {
+        let (bytes, size) = (self.corrupted_bytes.clone(), self.size);
+        let mut grid = Grid::new(size, size, '.');
+        let mut path: Vec<Point> = find_path(&grid).path().unwrap_or_default();
+        let mut fallen = 0;
+        return Box::new(std::iter::from_fn(move || {
+            // stop once the exit has been cut off
+            if path.is_empty() { return None; }
+            let byte = *bytes.get(fallen)?;
+            fallen += 1;
+            if let Some(tile) = grid.get_mut(Point::from(byte)) {
+                *tile = '#';
+            }
+            // only look for a new way out when this byte lands on the current one
+            if path.contains(&Point::from(byte)) {
+                path = find_path(&grid).path().unwrap_or_default();
+            }
+            let caption = match path.len() {
+                0 => format!("Byte {} at {},{} cuts off the exit", fallen, byte.0, byte.1),
+                steps => format!("{} bytes fallen, {} steps to the exit", fallen, steps - 1),
+            };
+            return Some(Frame::new(grid.clone()).with_path(path.clone()).with_highlights([byte]).with_caption(caption));
+        }));
+    }
+}
+
+// steps to the exit, None if it's been cut off
+fn part_1(corrupted_bytes: &[(usize, usize)], size: usize) -> Option<usize> {
+    let mut grid = Grid::new(size, size, '.');
+    for byte in corrupted_bytes {
+        if let Some(tile) = grid.get_mut(Point::from(*byte)) {
+            *tile = '#';
+        }
+    }
+    return find_path(&grid).goal_distance();
+}
+
+fn find_path(grid: &Grid<char>) -> Search<Point> {
+    // more pathfinding! start in top left corner, goal tile is bottom right tile
+    let goal = Point::new(grid.width() as i64 - 1, grid.height() as i64 - 1);
+    let successors = |position: &Point| grid.neighbors(position.to_index().unwrap())
+        .filter(|it| grid[*it] != '#')
+        .map(|it| (Point::from(it), 1));
+    return search::astar([Point::ORIGIN], successors, |position| position.manhattan(goal) as usize, |position| *position == goal);
+}
+
+// the first byte that cuts off the exit, None if the exit can still be reached once they've all fallen
+fn part_2(corrupted_bytes: &[(usize, usize)], size: usize) -> Option<(usize, usize)> {
+    if part_1(corrupted_bytes, size).is_some() {
+        return None;
+    }
+    // a space too small to have an exit has nothing to cut off
+    part_1(&[], size)?;
+    // once the exit is cut off it stays that way, so the first byte to do it can be found by halving
+    // the number of fallen bytes that still leave a way out and the number that don't
+    let (mut open, mut blocked) = (0, corrupted_bytes.len());
+    while blocked - open > 1 {
+        let middle = (open + blocked) / 2;
+        if part_1(&corrupted_bytes[..middle], size).is_none() { blocked = middle; } else { open = middle; }
+    }
+    return Some(corrupted_bytes[blocked - 1]);
+}
+
+// bytes falling on every tile of the real memory space in a random order, stopping once the given
+// number have fallen, but never before the exit has been cut off
+pub fn generate(rng: &mut Rng, bytes: usize) -> String {
+    loop {
+        let mut tiles: Vec<(usize, usize)> = (0..SIZE).flat_map(|y| (0..SIZE).map(move |x| (x, y))).collect();
+        // the start and the exit are never corrupted
+        tiles.retain(|it| *it != (0, 0) && *it != (SIZE - 1, SIZE - 1));
+        rng.shuffle(&mut tiles);
+        // part 1 needs a way out after the first kilobyte has fallen
+        if part_1(&tiles[..FALLEN], SIZE).is_none() { continue; }
+        // the first byte that blocks the exit, searching for it like part 2 would be slow
+        let (mut open, mut blocked) = (FALLEN, tiles.len());
+        while blocked - open > 1 {
+            let middle = (open + blocked) / 2;
+            if part_1(&tiles[..middle], SIZE).is_none() { blocked = middle; } else { open = middle; }
+        }
+        return tiles[..bytes.max(blocked + 1).min(tiles.len())].iter().map(|(x, y)| format!("{},{}\n", x, y)).collect();
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::{HashMap, HashSet};
+
+use util::random::Rng;
+use util::{Answer, InputError, Line, Solution};
+
+pub struct Day19 {
+    stock: HashSet<String>,
+    designs: Vec<String>,
+}
+
+impl Solution for Day19 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut lines = util::numbered_lines(input);
+        let top = lines.next().ok_or_else(|| InputError::missing("Expected a list of towel patterns but the input is empty"))?;
+        let stock: HashSet<String> = top.text.trim().split(", ").map(|it| it.to_owned()).collect();
+        if stock.iter().any(|it| it.is_empty()) {
+            return Err(InputError::on_line(top.number, "Expected towel patterns to not be empty"));
+        }
+        for pattern in top.text.trim().split(", ") {
+            check_stripes(&top, pattern)?;
+        }
+        // the patterns and designs are separated by a blank line
+        if let Some(line) = lines.next() {
+            if !line.text.is_empty() {
+                return Err(InputError::on_line(line.number, "Expected a blank line after the towel patterns"));
+            }
+        }
+        let mut designs: Vec<String> = vec![];
+        for line in lines {
+            check_stripes(&line, line.text)?;
+            designs.push(line.text.to_owned());
+        }
+        return Ok(Day19 { stock, designs });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&self.designs, &self.stock).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        // find the maximum length of a pattern in the stock
+        let max_pattern_size = self.stock.iter().map(|it| it.len()).max().unwrap();
+        part_2(&self.designs, &self.stock, max_pattern_size).into()
+    }
+}
+
+// towels and designs are only white (w), blue (u), black (b), red (r) and green (g) stripes, which
+// also keeps the designs to one byte per stripe for slicing
+fn check_stripes(line: &Line, token: &str) -> Result<(), InputError> {
+    if let Some((index, colour)) = token.char_indices().find(|(_, it)| !"wubrg".contains(*it)) {
+        return Err(line.error(&token[index..index + colour.len_utf8()], format!("Expected a stripe colour (w, u, b, r or g) but found '{}'", colour)));
+    }
+    return Ok(());
+}
+
+fn part_1(designs: &[String], stock: &HashSet<String>) -> usize {
+    // keep track of designs we've already checked to reduce recomputing
+    let mut map: HashMap<String, bool> = HashMap::new();
+
+    let mut possible_designs = 0;
+    for design in designs {
+        if find_design(design, stock, &mut map) {
+            possible_designs += 1;
+        }
+    }
+    return possible_designs;
+}
+
+fn part_2(designs: &[String], stock: &HashSet<String>, max_pattern_size: usize) -> usize {
+    // keep track of how many ways to make a design, memoization!
+    let mut map: HashMap<String, usize> = HashMap::new();
+
+    let mut possible_arrangements = 0;
+    for design in designs {
+        possible_arrangements += find_designs(design, stock, &mut map, max_pattern_size);
+    }
+    return possible_arrangements;
+}
+
+fn find_design(design: &str, stock: &HashSet<String>, map: &mut HashMap<String, bool>) -> bool {
+    if design.is_empty() { return true; }
+    // check the map first
+    if map.contains_key(design) {
+        return map[design];
+    }
+    // if the design is only one in length, it either can be made or not
+    if design.len() == 1 {
+        let result = stock.contains(design);
+        map.insert(design.to_owned(), result);
+        return result;
+    }
+    // otherwise, try to match the biggest section possible
+    // start by reducing the length by 1 each time
+    for final_index in (1..=design.len()).rev() {
+        // for each new size, we need to shift over to check all possibilities, segmenting the
+        // design into 3 sections
+        for shift in 0..=design.len() - final_index { 
+            // make sure rest of design can be matched, aka segments 1 and 3
+            if stock.contains(&design[shift..final_index + shift])
+            && find_design(&design[..shift], stock, map) && find_design(&design[final_index + shift..], stock, map) { 
+                map.insert(design.to_owned(), true);
+                return true; 
+            }
+        }
+    }
+    map.insert(design.to_owned(), false);
+    return false;
+}
+
+fn find_designs(design: &str, stock: &HashSet<String>, map: &mut HashMap<String, usize>, max_pattern_size: usize) -> usize {
+    let mut possibilities = 0;
+    // check the map first
+    if map.contains_key(design) {
+        return map[design];
+    }
+    // similar to part 1 but only segment into 2 sections because that makes way more sense
+    for index in 0..design.len() {
+        // if our length is longer than the maximum available pattern there will be no more matches
+        if index > max_pattern_size - 1 { break; }
+        // get the remaining section of the pattern
+        let second = &design[..index + 1];
+        if stock.contains(second) {
+            // it can be made, check the first segment
+            let first = &design[index + 1..];
+            if stock.contains(first) {
+                possibilities += 1;
+            }
+            if map.contains_key(first) {
+                possibilities += map[first]; 
+                // continue so we don't double count anything
+                continue;
+            }
+            possibilities += find_designs(&design[index + 1..], stock, map, max_pattern_size);
+        } 
+    }
+    map.insert(design.to_owned(), possibilities);
+    return possibilities;
+}
+
+// towel patterns, without a single 'g' stripe so some designs can't be made, then the designs
+pub fn generate(rng: &mut Rng, designs: usize) -> String {
+    const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+    let mut patterns: Vec<String> = ["w", "u", "b", "r"].map(|it| it.to_owned()).to_vec();
+    while patterns.len() < 150 {
+        let pattern: String = (0..rng.range(2..=8)).map(|_| *rng.pick(&COLORS)).collect();
+        if !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+    rng.shuffle(&mut patterns);
+
+    let mut output = patterns.join(", ") + "\n\n";
+    for _ in 0..designs {
+        let length = rng.range(20..=60) as usize;
+        let mut design = String::new();
+        // most designs are patterns stuck together, the rest are random stripes
+        while design.len() < length {
+            if rng.chance(0.9) { let pattern = rng.pick(&patterns); design.push_str(pattern); } else { design.push(*rng.pick(&COLORS)); }
+        }
+        output.push_str(&design);
+        output.push('\n');
+    }
+    return output;
+}
@@ -0,0 +1,163 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use util::random::{self, Rng};
+use util::search;
+use util::viz::{Animate, Frame};
+use util::{Answer, Direction, Grid, InputError, Point, Solution};
+
+// a cheat only counts if it saves at least this many picoseconds, the example uses smaller ones
+const THRESHOLD: usize = 100;
+
+pub struct Day20 {
+    grid: Grid<char>,
+    start: Point,
+    threshold: usize,
+}
+
+impl Day20 {
+    pub fn with_threshold(self, threshold: usize) -> Self {
+        return Day20 { threshold, ..self };
+    }
+}
+
+impl Solution for Day20 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let grid = util::try_parse_grid(input)?;
+        // the race always begins on S
+        let start = grid.find(&'S').map(Point::from).ok_or_else(|| InputError::missing("Expected a start tile 'S' on the racetrack"))?;
+        return Ok(Day20 { grid, start, threshold: THRESHOLD });
+    }
+
+    fn part_1(&self) -> Answer {
+        let scores = find_base_scores(&self.grid, self.start);
+        part_1(&self.grid, &scores, self.threshold).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let scores = find_base_scores(&self.grid, self.start);
+        part_2(&self.grid, &scores, self.threshold).into()
+    }
+}
+
+impl Animate for Day20 {
+    // the track, then every two picosecond cheat that saves enough time from the biggest saving down
+    fn frames(&self) -> Box<dyn Iterator<Item = Frame>> {
+        let scores = find_base_scores(&self.grid, self.start);
+        let mut cheats = find_cheats(&self.grid, &scores, self.threshold);
+        cheats.sort_by_key(|(saved, start, _, _)| (Reverse(*saved), start.y, start.x));
+
+        let track: Vec<Point> = scores.keys().copied().collect();
+        let first = Frame::new(self.grid.clone())
+            .with_visited(track.clone())
+            .with_caption(format!("{} picoseconds without cheating, {} cheats save at least {}", scores.len() - 1, cheats.len(), self.threshold));
+        let grid = self.grid.clone();
+        let count = cheats.len();
+        let frames = cheats.into_iter().enumerate().map(move |(index, (saved, start, wall, end))| {
+            return Frame::new(grid.clone())
+                .with_visited(track.clone())
+                .with_highlights([start, wall, end])
+                .with_caption(format!("Cheat {} of {} from {} to {} saves {} picoseconds", index + 1, count, start, end, saved));
+        });
+        return Box::new([first].into_iter().chain(frames));
+    }
+}
+
+fn part_1(grid: &Grid<char>, scores: &HashMap<Point, usize>, threshold: usize) -> usize {
+    return find_cheats(grid, scores, threshold).len();
+}
+
+// what each cheat saving at least threshold saves, where it starts, the wall it goes through and
+// where it ends
+fn find_cheats(grid: &Grid<char>, scores: &HashMap<Point, usize>, threshold: usize) -> Vec<(usize, Point, Point, Point)> {
+    // for every possible "cheat" (phase through walls for 2 seconds) compute how much time it
+    // saves by calculating destination score - starting score
+    let mut good_cheats = vec![];
+    for point in scores.keys() {
+        for direction in Direction::ALL {
+            let destination1 = *point + direction;
+            let destination2 = destination1 + direction; 
+            if !grid.contains(destination1) || !grid.contains(destination2) { 
+                // this cheat takes us out of bounds, skip
+                continue; 
+            }
+            if grid[destination1] != '#' {
+                // this cheat isn't actually a cheat
+                continue;
+            }
+            // if we land on a valid tile
+            if scores.contains_key(&destination2) {
+                let initial_score = scores[point];
+                let destination_score = scores[&destination2];
+                // remember to account for the 2 steps taken during the cheat
+                if destination_score > initial_score && destination_score - initial_score >= threshold + 2 { 
+                    good_cheats.push((destination_score - initial_score - 2, *point, destination1, destination2));
+                }
+            }
+        }
+    } 
+    return good_cheats;
+}
+
+fn part_2(grid: &Grid<char>, scores: &HashMap<Point, usize>, threshold: usize) -> usize {
+    // this time we need to consider all points in a 20 second radius
+    let mut good_cheats = 0;
+    for point in scores.keys() {
+        for (destination, distance) in get_tiles(*point, grid) {
+            if grid[destination] == '#' {
+                // this cheat lands us in a wall
+                continue;
+            }
+            // if we land on a valid tile
+            if scores.contains_key(&destination) {
+                let initial_score = scores[point];
+                let destination_score = scores[&destination];
+                // remember to account for the steps taken during the cheat
+                if destination_score > initial_score && destination_score - initial_score >= threshold + distance { 
+                    good_cheats += 1; 
+                }
+            }
+        }
+    } 
+
+    return good_cheats;
+}
+
+fn find_base_scores(grid: &Grid<char>, start: Point) -> HashMap<Point, usize> {
+    // first, do a normal path find to figure out how long it takes to get to each tile on the grid
+    let successors = |position: &Point| Direction::ALL.map(|it| *position + it).into_iter()
+        // skip walls
+        .filter(|it| grid.get(*it).is_some_and(|tile| *tile != '#'));
+    return search::bfs([start], successors, |_| false).distances;
+}
+
+fn get_tiles(point: Point, grid: &Grid<char>) -> Vec<(Point, usize)> {
+    let mut list: Vec<(Point, usize)> = vec![];
+    // get all tiles on the grid and their distance within a 20 tile distance
+    for x in -20..=20 {
+        for y in -20..=20 {
+            let destination = point + Point::new(x, y);
+            let distance = point.manhattan(destination);
+            if !(1..=20).contains(&distance) || !grid.contains(destination) { continue; }
+            list.push((destination, distance as usize));
+        }
+    }
+    return list;
+}
+
+// a square racetrack, one winding path from S to E through solid walls
+pub fn generate(rng: &mut Rng, side: usize) -> String {
+    let maze = random::maze(rng, side, side);
+    let (width, height) = (maze.width(), maze.height());
+    let (start, end) = (Point::new(1, height as i64 - 2), Point::new(width as i64 - 2, 1));
+    let successors = |position: &Point| Direction::ALL.map(|it| *position + it).into_iter().filter(|it| maze[*it] != '#');
+    // there is only one way between two tiles of a maze, everything off it becomes wall
+    let path = search::bfs([start], successors, |it| *it == end).path().unwrap();
+    let mut grid = Grid::new(width, height, '#');
+    for position in path {
+        grid[position] = '.';
+    }
+    grid[start] = 'S';
+    grid[end] = 'E';
+    return grid.to_string();
+}
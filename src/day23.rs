@@ -0,0 +1,125 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use util::random::Rng;
+use util::{Answer, InputError, Solution};
+
+pub struct Day23 {
+    graph: HashMap<String, Vec<String>>,
+    to_check: HashSet<String>,
+}
+
+impl Solution for Day23 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+        let mut to_check: HashSet<String> = HashSet::new();
+        for line in util::numbered_lines(input) {
+            let (left, right) = line.split_once(line.text, "-")?;
+            graph.entry(left.to_owned()).or_default().push(right.to_owned());
+            graph.entry(right.to_owned()).or_default().push(left.to_owned());
+            if left.starts_with('t') {
+                to_check.insert(left.to_owned()); 
+            }
+        }
+        return Ok(Day23 { graph, to_check });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&self.graph, &self.to_check).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(&self.graph).join(",").into()
+    }
+}
+
+fn part_1(graph: &HashMap<String, Vec<String>>, to_check: &HashSet<String>) -> usize {
+    // keep track of computers starting with t that we've handled, this prevents double counting if
+    // a set of three contains two or more computers starting with t
+    let mut checked: HashSet<String> = HashSet::new();
+    let mut sum = 0;
+    for computer in to_check.iter() {
+        let connections = &graph[computer];
+        for (one, first) in connections.iter().enumerate() {
+            if checked.contains(first) { continue; }
+            for second in &connections[one + 1..] {
+                if checked.contains(second) { continue; }
+                if graph[first].contains(second) {
+                    checked.insert(computer.to_owned());
+                    sum += 1;
+                }
+            }
+        } 
+    }
+    return sum;
+}
+
+fn part_2(graph: &HashMap<String, Vec<String>>) -> Vec<String> {
+    let mut best_network: HashSet<&String> = HashSet::new();
+
+    for computer in graph.keys() {
+        // find this computer's largest network
+        let mut best_inner_network: HashSet<&String> = HashSet::new();
+        let connections = &graph[computer];
+
+        // if number of connections is lower than our current best network this can't be any better
+        if connections.len() < best_network.len() { continue; }
+
+        for connection in connections {
+            // track the network formed with this connection
+            let mut inner_network: HashSet<&String> = HashSet::new();
+            inner_network.insert(computer);
+            inner_network.insert(connection);
+
+            // if additional less than current best inner network this can't be any better
+            if connections.len() - 2 < best_inner_network.len() { continue; }
+
+            // see if any others can be added to the network
+            'add_connection: for additional in connections {
+                for existing in &inner_network {
+                    if !graph[*existing].contains(additional) { continue 'add_connection; }
+                }
+                inner_network.insert(additional);
+            }
+            
+            // check if this new network beats the existing inner one
+            if inner_network.len() > best_inner_network.len() {
+                best_inner_network = inner_network;
+            }
+        }
+        // check if this computer's best network beats the current overall best
+        if best_inner_network.len() > best_network.len() {
+            best_network = best_inner_network;
+        }
+    }
+    
+    let mut return_value: Vec<String> = best_network.into_iter().map(|it| it.to_owned()).collect();
+    return_value.sort();
+    return return_value;
+}
+
+// connections between computers with two letter names, every computer is linked to about a dozen
+// others and one group of 13 is connected to each other
+pub fn generate(rng: &mut Rng, computers: usize) -> String {
+    let mut names: Vec<String> = (b'a'..=b'z').flat_map(|first| (b'a'..=b'z').map(move |second| format!("{}{}", first as char, second as char))).collect();
+    rng.shuffle(&mut names);
+    names.truncate(computers.clamp(13, names.len()));
+
+    // ordered so the same seed gives the same input
+    let mut links: BTreeSet<(usize, usize)> = BTreeSet::new();
+    for first in 0..13 {
+        for second in first + 1..13 {
+            links.insert((first, second));
+        }
+    }
+    for computer in 0..names.len() {
+        for _ in 0..6 {
+            let other = rng.below(names.len());
+            if other != computer {
+                links.insert((computer.min(other), computer.max(other)));
+            }
+        }
+    }
+    let mut lines: Vec<String> = links.into_iter().map(|(first, second)| format!("{}-{}\n", names[first], names[second])).collect();
+    rng.shuffle(&mut lines);
+    return lines.concat();
+}
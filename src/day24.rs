@@ -0,0 +1,275 @@
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+
+use util::random::Rng;
+use util::{Answer, InputError, Line, Solution};
+
+#[derive(Debug, Clone)]
+struct Gate {
+    input1: String,
+    input2: String,
+    operation: i32,
+    output: String
+}
+
+pub struct Day24 {
+    wires: HashMap<String, bool>,
+    gates: HashMap<String, Gate>,
+}
+
+impl Solution for Day24 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut lines = util::numbered_lines(input);
+
+        let mut wires: HashMap<String, bool> = HashMap::new();
+        // deal with initial wire values
+        for line in lines.by_ref() {
+            if line.text.is_empty() { break; }
+            let (name, value) = line.split_once(line.text, ": ")?;
+            if name.is_empty() {
+                return Err(InputError::at(line.number, 1, "Expected a wire name before ': '"));
+            }
+            let value = match value {
+                "0" => false,
+                "1" => true,
+                _ => return Err(line.error(value, format!("Expected a wire value of 0 or 1 but found '{}'", value))),
+            };
+            wires.insert(name.to_owned(), value);
+        }
+
+        let mut gates: HashMap<String, Gate> = HashMap::new();
+        let mut gate_lines: Vec<Line> = vec![];
+        // deal with gates
+        for line in lines {
+            let gate = parse_gate(line)?;
+            gates.insert(gate.output.to_owned(), gate);
+            gate_lines.push(line);
+        } 
+
+        // every input has to have a value to start with or come out of a gate, and a gate can't
+        // depend on its own output, or working out the wires would never finish
+        for line in &gate_lines {
+            let segments: Vec<&str> = line.text.split(" ").collect();
+            for input in [segments[0], segments[2]] {
+                if !wires.contains_key(input) && !gates.contains_key(input) {
+                    return Err(line.error(input, format!("Nothing sets wire '{}'", input)));
+                }
+            }
+            if depends_on(segments[4], segments[4], &gates, &mut HashSet::new()) {
+                return Err(line.error(segments[4], format!("Wire '{}' depends on itself", segments[4])));
+            }
+        }
+        return Ok(Day24 { wires, gates });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&mut self.evaluate(), &self.gates).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        part_2(&self.gates).join(",").into()
+    }
+}
+
+impl Day24 {
+    // replaces the starting values of the x and y wires with the bits of two numbers, so the circuit
+    // can be tried on other inputs, bits past the last x or y wire are dropped
+    pub fn with_inputs(mut self, x: u64, y: u64) -> Self {
+        for (name, value) in self.wires.iter_mut() {
+            let Some(Ok(bit)) = name.get(1..).map(|it| it.parse::<u32>()) else { continue; };
+            match name.chars().next() {
+                Some('x') => *value = x.checked_shr(bit).unwrap_or(0) & 1 == 1,
+                Some('y') => *value = y.checked_shr(bit).unwrap_or(0) & 1 == 1,
+                _ => {},
+            }
+        }
+        return self;
+    }
+
+    // the value of every wire once all the gates have settled
+    pub fn evaluate(&self) -> HashMap<String, bool> {
+        let mut wires = self.wires.clone();
+        for gate in self.gates.values() {
+            if !wires.contains_key(&gate.output) {
+                process_gate(gate, &mut wires, &self.gates);
+            }
+        }
+        return wires;
+    }
+}
+
+fn part_1(wires: &mut HashMap<String, bool>, gates: &HashMap<String, Gate>) -> i64 {
+    // by the end all wires starting with z need to have values
+    let bits: Vec<&String> = gates.keys().filter(|it| it.starts_with('z')).collect();
+    for bit in &bits {
+        if !wires.contains_key(*bit) {
+            process_gate(&gates[*bit], wires, gates);
+        }
+    }
+   
+    // now sort the bits and convert to a decimal number, 0 if there aren't any
+    let mut number = 0;
+    for bit in bits.into_iter().sorted().rev() {
+        number = (number << 1) | wires[bit] as i64;
+    }
+    number
+}
+
+fn part_2(gates: &HashMap<String, Gate>) -> Vec<String> {
+    let mut suspicious_gates: HashSet<String> = HashSet::new();
+    // wires that start with a value don't come out of a gate
+    let operation = |wire: &String| gates.get(wire).map(|it| it.operation);
+    // the carry out of the top bit, z45 for the puzzle's 45 bit numbers
+    let last = gates.keys().filter(|it| it.starts_with('z')).max().cloned().unwrap_or_default();
+
+    // because this is a ripple adder we can check a few scenarios
+    for gate in gates.values() {
+        // this is an output bit and not the first or last one
+        // it must be an xor operation
+        if gate.output.starts_with('z') && gate.output != "z00" && gate.output != last && gate.operation != 2 { 
+            suspicious_gates.insert(gate.output.clone()); 
+        } 
+
+        if gate.operation == 2 && !gate.input1.starts_with('x') && !gate.input2.starts_with('x') && gate.output != "z01" {
+            // this is an "intermediate" and, make sure this outputs a final "z" bit
+            if !gate.output.starts_with('z') {
+                suspicious_gates.insert(gate.output.clone());
+            }
+            // make sure no inputs come from and operations
+            if operation(&gate.input1) == Some(0) {
+                suspicious_gates.insert(gate.input1.clone());
+            }
+            if operation(&gate.input2) == Some(0) {
+                suspicious_gates.insert(gate.input2.clone());
+            }
+        }
+
+        if gate.operation == 1 {
+            // this is an or for the carry bit, make sure no inputs come from xor operations
+            if operation(&gate.input1) == Some(2) {
+                suspicious_gates.insert(gate.input1.clone());
+            }
+            if operation(&gate.input2) == Some(2) {
+                suspicious_gates.insert(gate.input2.clone());
+            }
+        }
+    }
+    
+    suspicious_gates.into_iter().sorted().collect()
+}
+
+fn process_gate(gate: &Gate, wires: &mut HashMap<String, bool>, gates: &HashMap<String, Gate>) {
+    if !wires.contains_key(&gate.input1) {
+        // no value, recursively sovle for the gate that outputs to this wire
+        process_gate(&gates[&gate.input1], wires, gates);
+    }
+    if !wires.contains_key(&gate.input2) {
+        // no value, recursively sovle for the gate that outputs to this wire
+        process_gate(&gates[&gate.input2], wires, gates);
+    }
+
+    // apply gate operation and save to output
+    let result = match gate.operation {
+        0 => wires[&gate.input1] & wires[&gate.input2],
+        1 => wires[&gate.input1] | wires[&gate.input2],
+        2 => wires[&gate.input1] ^ wires[&gate.input2],
+        _ => unreachable!()
+    };
+    wires.insert(gate.output.to_owned(), result);
+}
+
+// whether wire is worked out from target, following the gates back towards the starting wires
+fn depends_on(wire: &str, target: &str, gates: &HashMap<String, Gate>, seen: &mut HashSet<String>) -> bool {
+    let Some(gate) = gates.get(wire) else { return false; };
+    for input in [&gate.input1, &gate.input2] {
+        if input == target {
+            return true;
+        }
+        if seen.insert(input.clone()) && depends_on(input, target, gates, seen) {
+            return true;
+        }
+    }
+    return false;
+}
+
+fn parse_gate(line: Line) -> Result<Gate, InputError> {
+    // gates look like "x00 AND y00 -> z00"
+    let segments: Vec<&str> = line.text.split(" ").collect();
+    if segments.len() != 5 || segments[3] != "->" {
+        return Err(InputError::on_line(line.number, format!("Expected a gate like 'x00 AND y00 -> z00' but found '{}'", line.text)));
+    }
+    let operation = match segments[1] {
+        "AND" => 0,
+        "OR" => 1,
+        "XOR" => 2,
+        _ => return Err(line.error(segments[1], format!("Unknown gate operation '{}'", segments[1]))),
+    };
+    return Ok(Gate { input1: segments[0].to_owned(), input2: segments[2].to_owned(), operation, output: segments[4].to_owned() });
+}
+
+// a ripple carry adder for numbers with the given number of bits, with four pairs of gate outputs
+// swapped the way the puzzle swaps them
+pub fn generate(rng: &mut Rng, bits: usize) -> String {
+    // the swaps need four bits that aren't the first two or the last
+    let bits = bits.clamp(7, 60);
+    let mut names: Vec<String> = vec![];
+    let mut name = |rng: &mut Rng| loop {
+        let name: String = (0..3).map(|_| (b'a' + rng.below(23) as u8) as char).collect();
+        if !names.contains(&name) {
+            names.push(name.clone());
+            return name;
+        }
+    };
+
+    // for every bit: the inputs xor'ed and and'ed, the sum, the carry and'ed in, and the carry out
+    let mut gates: Vec<[String; 4]> = vec![];
+    let mut outputs: Vec<[String; 5]> = vec![];
+    let mut carry = String::new();
+    for bit in 0..bits {
+        let (x, y, z) = (format!("x{:02}", bit), format!("y{:02}", bit), format!("z{:02}", bit));
+        if bit == 0 {
+            carry = name(rng);
+            gates.push([x.clone(), "XOR".to_owned(), y.clone(), z.clone()]);
+            gates.push([x, "AND".to_owned(), y, carry.clone()]);
+            continue;
+        }
+        let next = if bit == bits - 1 { format!("z{:02}", bits) } else { name(rng) };
+        let wires = [name(rng), name(rng), z, name(rng), next.clone()];
+        gates.push([x.clone(), "XOR".to_owned(), y.clone(), wires[0].clone()]);
+        gates.push([x, "AND".to_owned(), y, wires[1].clone()]);
+        gates.push([wires[0].clone(), "XOR".to_owned(), carry.clone(), wires[2].clone()]);
+        gates.push([wires[0].clone(), "AND".to_owned(), carry, wires[3].clone()]);
+        gates.push([wires[1].clone(), "OR".to_owned(), wires[3].clone(), next.clone()]);
+        outputs.push(wires);
+        carry = next;
+    }
+
+    // swap the sum with one of the other outputs of its bit, or the two gates on the inputs
+    let mut swapped: Vec<usize> = (2..bits - 1).collect();
+    rng.shuffle(&mut swapped);
+    for bit in &swapped[..4] {
+        let wires = &outputs[*bit - 1];
+        let (first, second) = match rng.below(4) {
+            0 => (wires[2].clone(), wires[1].clone()),
+            1 => (wires[2].clone(), wires[3].clone()),
+            2 => (wires[2].clone(), wires[4].clone()),
+            _ => (wires[0].clone(), wires[1].clone()),
+        };
+        for gate in gates.iter_mut() {
+            if gate[3] == first { gate[3] = second.clone(); } else if gate[3] == second { gate[3] = first.clone(); }
+        }
+    }
+
+    let mut output = String::new();
+    for input in ["x", "y"] {
+        for bit in 0..bits {
+            output.push_str(&format!("{}{:02}: {}\n", input, bit, rng.below(2)));
+        }
+    }
+    output.push('\n');
+    rng.shuffle(&mut gates);
+    for [left, operation, right, result] in gates {
+        output.push_str(&format!("{} {} {} -> {}\n", left, operation, right, result));
+    }
+    return output;
+}
@@ -0,0 +1,99 @@
+use util::random::Rng;
+use util::{Answer, InputError, Line, Solution};
+
+pub struct Day25 {
+    locks: Vec<[i32; 5]>,
+    keys: Vec<[i32; 5]>,
+}
+
+impl Solution for Day25 {
+    fn parse(input: &str) -> Result<Self, InputError> {
+        let mut locks: Vec<[i32; 5]> = vec![];
+        let mut keys: Vec<[i32; 5]> = vec![];
+
+        let mut lines = util::numbered_lines(input);
+
+        while let Some(line) = lines.next() {
+            let line = check_row(Some(line))?;
+            if line.text.starts_with('#') {
+                // this is a lock
+                let mut lock = [0; 5];
+                for _ in 0..6 {
+                    for (index, character) in check_row(lines.next())?.text.chars().enumerate() {
+                        if character == '#' {
+                            lock[index] += 1;
+                        }
+                    }
+                }
+                locks.push(lock);
+            }
+            else {
+                // this is a key
+                let mut key = [0; 5];
+                for _ in 0..5 {
+                    for (index, character) in check_row(lines.next())?.text.chars().enumerate() {
+                        if character == '#' {
+                            key[index] += 1;
+                        }
+                    }
+                }
+                // ignore the bottom row of the key
+                check_row(lines.next())?;
+                keys.push(key);
+            }
+            lines.next();
+        }
+        return Ok(Day25 { locks, keys });
+    }
+
+    fn part_1(&self) -> Answer {
+        part_1(&self.locks, &self.keys).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        // there is no second puzzle on the last day, just deliver the chronicle!
+        "Merry Christmas!".into()
+    }
+}
+
+fn check_row(line: Option<Line>) -> Result<Line, InputError> {
+    // every schematic is made of seven rows of five '#' or '.' characters
+    let line = line.ok_or_else(|| InputError::missing("Expected another schematic row but the input ended"))?;
+    if line.text.len() != 5 || !line.text.chars().all(|it| it == '#' || it == '.') {
+        return Err(InputError::on_line(line.number, format!("Expected a schematic row like '#.##.' but found '{}'", line.text)));
+    }
+    return Ok(line);
+}
+
+fn part_1(locks: &[[i32; 5]], keys: &[[i32; 5]]) -> usize {
+    let mut sum = 0;
+    for lock in locks {
+        'pair: for key in keys {
+            // check heights for each column, can't be greater than 5
+            for column in 0..5 {
+                if lock[column] + key[column] > 5 {
+                    continue 'pair; 
+                }
+            }
+            // this pair was valid
+            sum += 1;
+        }
+    }
+    return sum;
+}
+
+// locks and keys with random pin heights, about as many of each
+pub fn generate(rng: &mut Rng, schematics: usize) -> String {
+    let mut blocks: Vec<String> = vec![];
+    for _ in 0..schematics {
+        let heights: Vec<usize> = (0..5).map(|_| rng.below(6)).collect();
+        let lock = rng.chance(0.5);
+        // locks fill from the top row down, keys from the bottom row up
+        let rows: Vec<String> = (0..7).map(|row| (0..5).map(|column| {
+            let filled = if lock { row <= heights[column] } else { 6 - row <= heights[column] };
+            return if filled { '#' } else { '.' };
+        }).collect()).collect();
+        blocks.push(rows.join("\n") + "\n");
+    }
+    return blocks.join("\n");
+}
@@ -0,0 +1,120 @@
+// every day's solver, usable from other crates as well as the binaries, which only pick a day and run it
+// each dayNN module has a DayNN type whose Solution impl parses an input and answers both parts, the
+// functions behind the parts stay private, see "Using the days as a library" in the README
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+// every binary linking the days gets the counting allocator when the feature is on
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: util::alloc::CountingAllocator = util::alloc::CountingAllocator;
+
+// parses the input and runs one part, or both parts when no part is given
+pub type Solver = fn(&str, Option<usize>) -> Result<util::Run, util::InputError>;
+
+pub fn solver(day: usize) -> Option<Solver> {
+    return match day {
+        1 => Some(util::solve::<day01::Day01>),
+        2 => Some(util::solve::<day02::Day02>),
+        3 => Some(util::solve::<day03::Day03>),
+        4 => Some(util::solve::<day04::Day04>),
+        5 => Some(util::solve::<day05::Day05>),
+        6 => Some(util::solve::<day06::Day06>),
+        7 => Some(util::solve::<day07::Day07>),
+        8 => Some(util::solve::<day08::Day08>),
+        9 => Some(util::solve::<day09::Day09>),
+        10 => Some(util::solve::<day10::Day10>),
+        11 => Some(util::solve::<day11::Day11>),
+        12 => Some(util::solve::<day12::Day12>),
+        13 => Some(util::solve::<day13::Day13>),
+        14 => Some(util::solve::<day14::Day14>),
+        15 => Some(util::solve::<day15::Day15>),
+        16 => Some(util::solve::<day16::Day16>),
+        17 => Some(util::solve::<day17::Day17>),
+        18 => Some(util::solve::<day18::Day18>),
+        19 => Some(util::solve::<day19::Day19>),
+        20 => Some(util::solve::<day20::Day20>),
+        21 => Some(util::solve::<day21::Day21>),
+        22 => Some(util::solve::<day22::Day22>),
+        23 => Some(util::solve::<day23::Day23>),
+        24 => Some(util::solve::<day24::Day24>),
+        25 => Some(util::solve::<day25::Day25>),
+        _ => None,
+    };
+}
+
+// writes a random input for a day, the size is whatever scales that day (lines, side of a grid, bits...)
+pub type Generator = fn(&mut util::random::Rng, usize) -> String;
+
+// the generator and the size that makes an input about as big as the puzzle's
+pub fn generator(day: usize) -> Option<(Generator, usize)> {
+    return match day {
+        1 => Some((day01::generate, 1000)),
+        2 => Some((day02::generate, 1000)),
+        3 => Some((day03::generate, 750)),
+        4 => Some((day04::generate, 140)),
+        5 => Some((day05::generate, 200)),
+        6 => Some((day06::generate, 130)),
+        7 => Some((day07::generate, 850)),
+        8 => Some((day08::generate, 50)),
+        9 => Some((day09::generate, 19999)),
+        10 => Some((day10::generate, 50)),
+        11 => Some((day11::generate, 8)),
+        12 => Some((day12::generate, 140)),
+        13 => Some((day13::generate, 320)),
+        14 => Some((day14::generate, 500)),
+        15 => Some((day15::generate, 50)),
+        16 => Some((day16::generate, 141)),
+        17 => Some((day17::generate, 16)),
+        18 => Some((day18::generate, 3450)),
+        19 => Some((day19::generate, 400)),
+        20 => Some((day20::generate, 141)),
+        21 => Some((day21::generate, 5)),
+        22 => Some((day22::generate, 2000)),
+        23 => Some((day23::generate, 520)),
+        24 => Some((day24::generate, 45)),
+        25 => Some((day25::generate, 500)),
+        _ => None,
+    };
+}
+
+// parses the input and returns the frames showing how the day gets to its answers
+pub type Visualizer = fn(&str) -> Result<Box<dyn Iterator<Item = util::viz::Frame>>, util::InputError>;
+
+// only the days that work on a grid have frames
+pub fn visualizer(day: usize) -> Option<Visualizer> {
+    return match day {
+        6 => Some(util::viz::frames::<day06::Day06>),
+        10 => Some(util::viz::frames::<day10::Day10>),
+        12 => Some(util::viz::frames::<day12::Day12>),
+        14 => Some(util::viz::frames::<day14::Day14>),
+        15 => Some(util::viz::frames::<day15::Day15>),
+        16 => Some(util::viz::frames::<day16::Day16>),
+        18 => Some(util::viz::frames::<day18::Day18>),
+        20 => Some(util::viz::frames::<day20::Day20>),
+        _ => None,
+    };
+}
@@ -0,0 +1,307 @@
+// the 3-bit computer from day 17 on programs other than the puzzle's
+use std::fs;
+
+use advent_of_code_2024::day17::asm;
+use advent_of_code_2024::day17::debugger::Debugger;
+use advent_of_code_2024::day17::vm::{self, Fault, Registers, Stop, TraceEntry, Vm};
+use advent_of_code_2024::day17::{self, Day17, SearchError};
+use util::random::check;
+use util::{Answer, Solution};
+
+fn registers(a: i64, b: i64, c: i64) -> Registers {
+    return Registers { a, b, c };
+}
+
+#[test]
+fn small_examples() {
+    // the examples from the puzzle text, checking the registers left behind
+    let mut vm = Vm::new(&[2, 6], registers(0, 0, 9));
+    assert_eq!(vm.run(), Stop::Halt);
+    assert_eq!(vm.registers().b, 1);
+
+    let mut vm = Vm::new(&[5, 0, 5, 1, 5, 4], registers(10, 0, 0));
+    assert_eq!(vm.run(), Stop::Halt);
+    assert_eq!(vm.output(), [0, 1, 2]);
+
+    let mut vm = Vm::new(&[1, 7], registers(0, 29, 0));
+    vm.run();
+    assert_eq!(vm.registers().b, 26);
+
+    let mut vm = Vm::new(&[4, 0], registers(0, 2024, 43690));
+    vm.run();
+    assert_eq!(vm.registers().b, 44354);
+}
+
+#[test]
+fn stepping_through_the_example() {
+    let path = format!("{}/tests/fixtures/day17.txt", env!("CARGO_MANIFEST_DIR"));
+    let day = Day17::parse(&fs::read_to_string(path).unwrap()).unwrap();
+    let mut vm = day.vm();
+    // adv 1 halves a before anything is output
+    assert_eq!(vm.step(), Ok(None));
+    assert_eq!(vm.registers().a, 364);
+    assert_eq!(vm.pointer(), 2);
+    assert_eq!(vm.run_until_output(), Ok(4));
+    assert_eq!(vm.run_until_output(), Ok(6));
+    assert_eq!(vm.run(), Stop::Halt);
+    assert_eq!(vm.output(), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+    // stopping is sticky
+    assert_eq!(vm.step(), Err(Stop::Halt));
+    assert_eq!(vm.run_until_output(), Err(Stop::Halt));
+}
+
+#[test]
+fn bad_programs_fault() {
+    let mut vm = Vm::new(&[8, 0], Registers::default());
+    assert_eq!(vm.run(), Stop::Fault(Fault::UnknownOpcode { pointer: 0, opcode: 8 }));
+
+    // combo operand 7 is reserved, but bxl takes a literal so 7 is fine there
+    let mut vm = Vm::new(&[1, 7, 2, 7], Registers::default());
+    assert_eq!(vm.run(), Stop::Fault(Fault::InvalidOperand { pointer: 2, operand: 7 }));
+    assert_eq!(vm.registers().b, 7);
+
+    let mut vm = Vm::new(&[1, 2, 5], Registers::default());
+    assert_eq!(vm.run(), Stop::Fault(Fault::MissingOperand { pointer: 2 }));
+
+    let mut vm = Vm::new(&[0, 5], registers(100, -1, 0));
+    assert_eq!(vm.run(), Stop::Fault(Fault::NegativeShift { pointer: 0, power: -1 }));
+
+    // shifting by more than 63 bits just leaves 0
+    let mut vm = Vm::new(&[6, 4], registers(i64::MAX, 0, 0));
+    assert_eq!(vm.run(), Stop::Halt);
+    assert_eq!(vm.registers().b, 0);
+}
+
+#[test]
+fn instruction_limit() {
+    // jumps back to the start for as long as a isn't 0, which is forever
+    let mut vm = Vm::new(&[3, 0], registers(1, 0, 0)).with_limit(Some(50));
+    assert_eq!(vm.run(), Stop::Fault(Fault::LimitReached { limit: 50 }));
+    assert_eq!(vm.executed(), 50);
+
+    let mut vm = Vm::new(&[3, 0], registers(1, 0, 0));
+    assert_eq!(vm.run(), Stop::Fault(Fault::LimitReached { limit: 1_000_000 }));
+
+    // a program counting a down to 0 gets as far as it needs without a limit
+    let mut vm = Vm::new(&[0, 1, 3, 0], registers(1 << 40, 0, 0)).with_limit(None);
+    assert_eq!(vm.run(), Stop::Halt);
+    assert_eq!(vm.executed(), 82);
+}
+
+#[test]
+fn tracing() {
+    let mut vm = Vm::new(&[2, 4, 1, 3, 5, 5], registers(13, 0, 0)).with_trace();
+    assert_eq!(vm.run(), Stop::Halt);
+    let trace = vm.trace();
+    assert_eq!(trace.len(), 3);
+    assert_eq!(trace[1], TraceEntry {
+        pointer: 2, opcode: 1, operand: 3, before: registers(13, 5, 0), after: registers(13, 6, 0), output: None,
+    });
+    assert_eq!(trace[2].output, Some(6));
+    assert_eq!(trace[2].to_string(), "  4: out 5 | A=13 B=6 C=0 | out 6");
+
+    // nothing is kept unless asked for
+    let mut vm = Vm::new(&[2, 4, 1, 3, 5, 5], registers(13, 0, 0));
+    vm.run();
+    assert!(vm.trace().is_empty());
+}
+
+#[test]
+fn disassembly() {
+    let program = asm::find_program("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,1,7,5,4,0,0,3,5,5,3,0\n").unwrap();
+    assert_eq!(asm::disassemble(&program), "\
+l0:
+    bst A       ; 0: B = A % 8
+    bxl 1       ; 2: B = B ^ 1
+    cdv B       ; 4: C = A / 2^B
+    bxc         ; 6: B = B ^ C
+    adv 3       ; 8: A = A / 2^3
+    out B       ; 10: output B % 8
+    jnz l0      ; 12: jump to l0 if A != 0
+");
+
+    // jumps into the middle of an instruction can't have a label, and combo operand 7 is only noted
+    assert_eq!(asm::disassemble(&[2, 7, 3, 1, 4, 3]), concat!(
+        "    bst 7       ; 0: combo operand 7 is reserved\n",
+        "    jnz 1       ; 2: jump to 1 if A != 0\n",
+        "    bxc 3       ; 4: B = B ^ C\n",
+    ));
+
+    let error = asm::find_program("Program: 0,1,5").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:1: Expected the program to be pairs of opcodes and operands");
+}
+
+#[test]
+fn assembly() {
+    // errors point at the operand, past comments and blank lines
+    let error = asm::assemble("; reads a reserved operand\n\n    bst 7\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:3:9: Combo operand 7 is reserved");
+
+    let source = "\
+start:  bxc
+        adv 1       ; halve a
+        out A
+        jnz start
+";
+    let program = asm::assemble(source).unwrap();
+    assert_eq!(asm::format_program(&program), "4,0,0,1,5,4,3,0");
+    let mut vm = Vm::new(&program, registers(20, 0, 0));
+    assert_eq!(vm.run(), Stop::Halt);
+    assert_eq!(vm.output(), [2, 5, 2, 1, 0]);
+
+    for (source, message) in [
+        ("mul 3", "1:1: Unknown instruction 'mul'"),
+        ("adv", "1:1: Expected an operand after 'adv'"),
+        ("bxl 8", "1:5: Expected an operand from 0 to 7 but found 8"),
+        ("out 4", "1:5: Expected a combo operand (0-3, A, B or C) but found '4'"),
+        ("bxl 1 2", "1:7: Unexpected '2' after the operand"),
+        ("jnz end", "1:5: Unknown label 'end'"),
+        ("a:\na: bxc", "2:1: Label 'a' is defined twice"),
+        ("2x: bxc", "1:1: Invalid label '2x'"),
+        ("bxc\nbxc\nbxc\nbxc\nend: jnz end", "5:10: Label 'end' is at 8, but jnz can only jump to 0-7"),
+    ] {
+        assert_eq!(asm::assemble(source).unwrap_err().to_string(), format!("<input>:{}", message));
+    }
+}
+
+#[test]
+fn assembly_round_trips() {
+    check(50, |rng| {
+        // any valid program, the instructions don't have to make sense together
+        let mut program: Vec<i64> = vec![];
+        for _ in 0..rng.range(1..=12) {
+            let opcode = rng.range(0..=7);
+            let operand = if vm::takes_combo(opcode) { rng.range(0..=6) } else { rng.range(0..=7) };
+            program.extend([opcode, operand]);
+        }
+        let source = asm::disassemble(&program);
+        let assembled = asm::assemble(&source).map_err(|why| format!("{}\n{}", why, source))?;
+        if assembled != program {
+            return Err(format!("Expected {:?} but assembled {:?} from\n{}", program, assembled, source));
+        }
+        return Ok(());
+    });
+}
+
+#[test]
+fn searching_for_a() {
+    // the part 2 example, and the part 1 example outputting its own answer, where the first bit of 729
+    // is shifted out before anything sees it
+    assert_eq!(day17::search(&[0, 3, 5, 4, 3, 0], &[0, 3, 5, 4, 3, 0]), Ok(117440));
+    assert_eq!(day17::search(&[0, 1, 5, 4, 3, 0], &[4, 6, 3, 5, 6, 3, 5, 2, 1, 0]), Ok(728));
+
+    // shaped like a real input, checked against counting up from 0 to a value that's known to work
+    let program = [2, 4, 1, 1, 7, 5, 4, 0, 0, 3, 5, 5, 3, 0];
+    let output = |a: i64| {
+        let mut vm = Vm::new(&program, registers(a, 0, 0));
+        vm.run();
+        return vm.output().to_vec();
+    };
+    let target = output(0o4321);
+    let lowest = (0..=0o4321).find(|it| output(*it) == target).unwrap();
+    assert_eq!(day17::search(&program, &target), Ok(lowest));
+
+    // out A always outputs the 3 bits that adv 3 is about to drop, so 7,7,7 is 0o777 but nothing ends in 8
+    assert_eq!(day17::search(&[5, 4, 0, 3, 3, 0], &[7, 7, 7]), Ok(0o777));
+    assert_eq!(day17::search(&[5, 4, 0, 3, 3, 0], &[7, 8]), Err(SearchError::NoSolution));
+    assert_eq!(day17::search(&[5, 4, 0, 3, 3, 0], &[]), Err(SearchError::NoSolution));
+    // a 0 at the end would need a to be 0 before the last time round the loop, which ends it first
+    assert_eq!(day17::search(&[5, 4, 0, 3, 3, 0], &[1, 0]), Err(SearchError::NoSolution));
+    // more outputs than fit in 63 bits
+    assert_eq!(day17::search(&[5, 4, 0, 3, 3, 0], &[7; 22]), Err(SearchError::NoSolution));
+}
+
+#[test]
+fn programs_the_search_cant_handle() {
+    for (program, why) in [
+        (&[0, 3, 5, 4][..], "expected a single loop, with jnz 0 as the last instruction and no other jumps"),
+        (&[0, 3, 3, 4, 5, 4, 3, 0], "expected a single loop, with jnz 0 as the last instruction and no other jumps"),
+        (&[0, 3, 3, 0], "expected one out in the loop"),
+        (&[0, 3, 5, 4, 5, 4, 3, 0], "expected one out in the loop"),
+        (&[0, 4, 5, 4, 3, 0], "expected a to only change by one adv 1, 2 or 3"),
+        (&[0, 1, 0, 2, 5, 4, 3, 0], "expected a to only change by one adv 1, 2 or 3"),
+        // b carries over from one time round the loop to the next
+        (&[1, 3, 0, 3, 5, 5, 3, 0], "expected b and c to be set from a before they're read"),
+        (&[2, 7, 0, 3, 5, 4, 3, 0], "it isn't a valid program"),
+    ] {
+        let error = day17::search(program, program).unwrap_err();
+        assert_eq!(error, SearchError::Unsupported(why.to_owned()), "{:?}", program);
+        assert_eq!(error.to_string(), format!("Can't search this program: {}", why));
+    }
+}
+
+#[test]
+fn debugging() {
+    let program = [0, 1, 5, 4, 3, 0];
+    let mut debugger = Debugger::new(Vm::new(&program, registers(729, 0, 0)));
+    let script = "break 4\nstep\ncontinue\nset a 8\nwatch 0\nunwatch\nwatch\ncontinue\nback 4\nlist\noutput\nquit\nstep\n";
+    let mut output: Vec<u8> = vec![];
+    debugger.run(script.as_bytes(), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let expected = [
+        "  0: adv 1       | A=729 B=0 C=0",
+        "(day17) Breakpoint at 4",
+        "(day17)   2: out A       | A=364 B=0 C=0",
+        "(day17) Output 4",
+        "Breakpoint at 4",
+        "  4: jnz 0       | A=364 B=0 C=0",
+        "(day17)   4: jnz 0       | A=8 B=0 C=0",
+        "(day17) Watching for 0 to be output",
+        "(day17) Not watching the output",
+        "(day17) Watching for any output",
+        // jnz and adv run before the watch on out stops it, ahead of the breakpoint
+        "(day17) Output 4",
+        "  4: jnz 0       | A=4 B=0 C=0",
+        // back through out, adv and jnz, then the change to a
+        "(day17)   4: jnz 0       | A=364 B=0 C=0",
+        "(day17)      0: adv 1",
+        "",
+    ];
+    assert!(output.starts_with(&expected.join("\n")), "{}", output);
+    // the listing is every instruction, then the output goes back to what it was at the breakpoint
+    assert!(output.ends_with("     2: out A\n>*   4: jnz 0\n(day17) 4\n(day17) "), "{}", output);
+    // quit stops reading commands, so the last step never ran
+    assert_eq!(debugger.vm().pointer(), 4);
+    assert_eq!(debugger.vm().registers().a, 364);
+}
+
+#[test]
+fn debugger_mistakes() {
+    let program = [0, 1, 5, 4, 3, 0];
+    let mut debugger = Debugger::new(Vm::new(&program, registers(1, 0, 0)));
+    let script = "break 3\ndelete 2\nset d 1\nset a x\nwatch x\nstep x\nback\njump 0\nstep 5\n";
+    let mut output: Vec<u8> = vec![];
+    debugger.run(script.as_bytes(), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    for message in [
+        "There's no instruction at 3",
+        "There's no breakpoint at 2",
+        "Expected register a, b or c but found 'd'",
+        "Expected a number for the register but found 'x'",
+        "Expected a number to watch for but found 'x'",
+        "Unknown command 'step x', try help",
+        "Already back at the start",
+        "Unknown command 'jump 0', try help",
+        "Output 0\nHalted\n  6: end         | A=0 B=0 C=0",
+    ] {
+        assert!(output.contains(message), "Expected '{}' in\n{}", message, output);
+    }
+    // nothing that went wrong is left to step back through
+    assert!(output.ends_with("(day17) \n"), "{}", output);
+}
+
+#[test]
+fn programs_without_an_answer_say_why() {
+    let input = |a: i64, program: &str| format!("Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", a, program);
+
+    // jumps back to the start forever, so part 1 runs into the instruction limit
+    let day = Day17::parse(&input(1, "3,0")).unwrap();
+    assert_eq!(day.part_1(), Answer::Unsolved("Stopped after 1000000 instructions".to_owned()));
+    // no out, so part 2 has nothing it can search for
+    assert_eq!(day.part_2(), Answer::Unsolved("Can't search this program: expected one out in the loop".to_owned()));
+
+    // out A outputs a's last 3 bits, so the loop can never output an 8
+    let day = Day17::parse(&input(7, "5,4,0,3,3,0")).unwrap().with_target(vec![7, 8]);
+    assert_eq!(day.part_1(), Answer::from("7"));
+    assert_eq!(day.part_2(), Answer::Unsolved("No value of register A outputs the target".to_owned()));
+}
@@ -0,0 +1,117 @@
+// inputs that used to panic part way through solving are either rejected when they're parsed or
+// leave the part unsolved, saying what's wrong with them
+use advent_of_code_2024::*;
+use util::viz::Animate;
+use util::{Answer, Solution};
+
+fn error<S: Solution>(input: &str) -> String {
+    return match S::parse(input) {
+        Ok(_) => panic!("Expected an error parsing {:?}", input),
+        Err(why) => why.to_string(),
+    };
+}
+
+fn unsolved(answer: Answer) -> String {
+    return match answer {
+        Answer::Unsolved(why) => why,
+        _ => panic!("Expected the part to be unsolved but the answer was {}", answer),
+    };
+}
+
+#[test]
+fn day02() {
+    // the dampener removing either level leaves one, which is safe
+    let day = day02::Day02::parse("1 9\n").unwrap();
+    assert_eq!(day.part_1(), Answer::from(0));
+    assert_eq!(day.part_2(), Answer::from(1));
+}
+
+#[test]
+fn day13() {
+    let machine = |prize: &str| format!("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: {}\n", prize);
+    assert_eq!(error::<day13::Day13>(&machine("Xé5, Y=5400")), "<input>:3:8: Expected 'X=' at the start of 'Xé5'");
+    assert_eq!(error::<day13::Day13>(&machine("X+8400, Y=5400")), "<input>:3:8: Expected 'X=' at the start of 'X+8400'");
+    assert_eq!(error::<day13::Day13>(&machine("X=8400, Y=5x00")), "<input>:3:18: Invalid value '5x00': invalid digit found in string");
+    assert_eq!(error::<day13::Day13>("Button A: X=94, Y+34\n"), "<input>:1:11: Expected 'X+' at the start of 'X=94'");
+
+    // blank lines after the last machine are fine, a machine cut short isn't
+    let day = day13::Day13::parse(&format!("{}\n{}\n\n", machine("X=8400, Y=5400"), machine("X=8400, Y=5400"))).unwrap();
+    assert_eq!(day.part_1(), Answer::from(560));
+    assert_eq!(error::<day13::Day13>(&format!("{}\nButton A: X+94, Y+34\n\n", machine("X=8400, Y=5400"))), "<input>: Expected a 'Button B' line but the input ended");
+}
+
+#[test]
+fn day14() {
+    assert_eq!(error::<day14::Day14>("p=0,4 v=3,-3\np=101,0 v=1,1\n"), "<input>:2:3: Expected an x position from 0 to 100 but found 101");
+    assert_eq!(error::<day14::Day14>("p=6,-1 v=3,-3\n"), "<input>:1:5: Expected a y position from 0 to 102 but found -1");
+
+    // fine in the real bathroom but not the example's
+    let day = day14::Day14::parse("p=0,4 v=3,-3\np=50,0 v=1,1\n").unwrap();
+    assert_eq!(day.with_size(11, 7).err().unwrap().to_string(), "<input>:2: Expected a position inside the 11x7 bathroom but found 50,0");
+
+    // robots faster than the bathroom is wide still wrap around inside it
+    let day = day14::Day14::parse("p=2,4 v=25,-30\n").unwrap().with_size(11, 7).unwrap();
+    assert_eq!(day.part_1(), Answer::from(0));
+    assert!(day.frames().last().unwrap().render(false).ends_with("1 seconds, safety factor 0\n"));
+}
+
+#[test]
+fn day18() {
+    assert_eq!(error::<day18::Day18>("1,2\n80,3\n"), "<input>:2:1: Expected a coordinate from 0 to 70 but found 80");
+    assert_eq!(error::<day18::Day18>("1,71\n"), "<input>:1:3: Expected a coordinate from 0 to 70 but found 71");
+
+    // nothing falls, so the exit is never cut off
+    let day = day18::Day18::parse("").unwrap().with_size(7);
+    assert_eq!(day.part_1(), Answer::from(12));
+    assert_eq!(unsolved(day.part_2()), "The exit is never cut off");
+
+    // a wall with a gap in it that nothing falls into
+    let day = day18::Day18::parse("0,1\n1,1\n2,1\n").unwrap().with_size(4);
+    assert_eq!(day.part_1(), Answer::from(6));
+    assert_eq!(unsolved(day.part_2()), "The exit is never cut off");
+
+    // the wall is finished by the last byte, and the bytes outside the smaller space are left out
+    let day = day18::Day18::parse("0,1\n9,9\n1,1\n2,1\n").unwrap().with_size(3).with_fallen(4);
+    assert_eq!(unsolved(day.part_1()), "The exit can't be reached");
+    assert_eq!(day.part_2(), Answer::Coordinate(2, 1));
+
+    // a space of one tile starts on the exit, and one of no tiles has no exit at all
+    let day = day18::Day18::parse("").unwrap().with_size(1);
+    assert_eq!(day.part_1(), Answer::from(0));
+    assert_eq!(unsolved(day.part_2()), "The exit is never cut off");
+    let day = day18::Day18::parse("0,0\n").unwrap().with_size(0);
+    assert_eq!(unsolved(day.part_1()), "The exit can't be reached");
+    assert_eq!(unsolved(day.part_2()), "The exit is never cut off");
+}
+
+#[test]
+fn day19() {
+    assert_eq!(error::<day19::Day19>("a\n\néa\n"), "<input>:1:1: Expected a stripe colour (w, u, b, r or g) but found 'a'");
+    assert_eq!(error::<day19::Day19>("r, wé\n\nrw\n"), "<input>:1:5: Expected a stripe colour (w, u, b, r or g) but found 'é'");
+    assert_eq!(error::<day19::Day19>("r, w\n\nrw\nbré\n"), "<input>:4:3: Expected a stripe colour (w, u, b, r or g) but found 'é'");
+}
+
+#[test]
+fn day22() {
+    let day = day22::Day22::parse("").unwrap();
+    assert_eq!(day.part_1(), Answer::from(0));
+    assert_eq!(day.part_2(), Answer::from(0));
+    // a secret of 0 stays 0, so the price never changes
+    let day = day22::Day22::parse("0\n").unwrap();
+    assert_eq!(day.part_2(), Answer::from(0));
+}
+
+#[test]
+fn day24() {
+    assert_eq!(error::<day24::Day24>("x00: 1\n\nx00 AND y00 -> z00\n"), "<input>:3:9: Nothing sets wire 'y00'");
+    assert_eq!(error::<day24::Day24>("x00: 1\n\nx00 OR abc -> z00\nz00 XOR x00 -> abc\n"), "<input>:3:15: Wire 'z00' depends on itself");
+    assert_eq!(error::<day24::Day24>(": 1\n\n"), "<input>:1:1: Expected a wire name before ': '");
+
+    // no z wires make a number with no bits in it
+    let day = day24::Day24::parse("x00: 1\ny00: 0\n\nx00 AND y00 -> abc\n").unwrap();
+    assert_eq!(day.part_1(), Answer::from(0));
+
+    // wires named with a multibyte character are left alone when the inputs are set
+    let day = day24::Day24::parse("é0: 1\nx0: 0\n\né0 OR x0 -> z00\n").unwrap().with_inputs(0, 0);
+    assert_eq!(day.part_1(), Answer::from(1));
+}
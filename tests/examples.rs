@@ -0,0 +1,210 @@
+// every day checked against the examples given in the puzzle text
+// only answers the puzzle actually publishes are checked, parts without one are left out
+use std::fs;
+
+use advent_of_code_2024::*;
+use util::{Answer, Solution};
+
+fn example<S: Solution>(name: &str) -> S {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    let input = fs::read_to_string(&path).unwrap_or_else(|why| panic!("Failed to read {}: {}", path, why));
+    return S::parse(&input).unwrap_or_else(|why| panic!("{}: {}", name, why));
+}
+
+#[test]
+fn day01() {
+    let day: day01::Day01 = example("day01.txt");
+    assert_eq!(day.part_1(), Answer::from(11));
+    assert_eq!(day.part_2(), Answer::from(31));
+}
+
+#[test]
+fn day02() {
+    let day: day02::Day02 = example("day02.txt");
+    assert_eq!(day.part_1(), Answer::from(2));
+    assert_eq!(day.part_2(), Answer::from(4));
+}
+
+#[test]
+fn day03() {
+    // the part 2 example still has every mul from the part 1 example in it
+    let day: day03::Day03 = example("day03.txt");
+    assert_eq!(day.part_1(), Answer::from(161));
+    assert_eq!(day.part_2(), Answer::from(48));
+}
+
+#[test]
+fn day04() {
+    let day: day04::Day04 = example("day04.txt");
+    assert_eq!(day.part_1(), Answer::from(18));
+    assert_eq!(day.part_2(), Answer::from(9));
+}
+
+#[test]
+fn day05() {
+    let day: day05::Day05 = example("day05.txt");
+    assert_eq!(day.part_1(), Answer::from(143));
+    assert_eq!(day.part_2(), Answer::from(123));
+}
+
+#[test]
+fn day06() {
+    let day: day06::Day06 = example("day06.txt");
+    assert_eq!(day.part_1(), Answer::from(41));
+    assert_eq!(day.part_2(), Answer::from(6));
+}
+
+#[test]
+fn day07() {
+    let day: day07::Day07 = example("day07.txt");
+    assert_eq!(day.part_1(), Answer::from(3749));
+    assert_eq!(day.part_2(), Answer::from(11387));
+}
+
+#[test]
+fn day08() {
+    let day: day08::Day08 = example("day08.txt");
+    assert_eq!(day.part_1(), Answer::from(14));
+    assert_eq!(day.part_2(), Answer::from(34));
+}
+
+#[test]
+fn day09() {
+    let day: day09::Day09 = example("day09.txt");
+    assert_eq!(day.part_1(), Answer::from(1928));
+    assert_eq!(day.part_2(), Answer::from(2858));
+}
+
+#[test]
+fn day10() {
+    let day: day10::Day10 = example("day10.txt");
+    assert_eq!(day.part_1(), Answer::from(36));
+    assert_eq!(day.part_2(), Answer::from(81));
+}
+
+#[test]
+fn day11() {
+    let day: day11::Day11 = example("day11.txt");
+    assert_eq!(day.part_1(), Answer::from(55312));
+}
+
+#[test]
+fn day12() {
+    let small: day12::Day12 = example("day12-small.txt");
+    assert_eq!(small.part_1(), Answer::from(140));
+    assert_eq!(small.part_2(), Answer::from(80));
+
+    let large: day12::Day12 = example("day12.txt");
+    assert_eq!(large.part_1(), Answer::from(1930));
+    assert_eq!(large.part_2(), Answer::from(1206));
+}
+
+#[test]
+fn day13() {
+    let day: day13::Day13 = example("day13.txt");
+    assert_eq!(day.part_1(), Answer::from(480));
+
+    // the equations can be solved with a negative number of presses, or more than the 100 part 1
+    // allows, neither of which wins the prize
+    let day = day13::Day13::parse("Button A: X+10, Y+20\nButton B: X+20, Y+10\nPrize: X=-30, Y=-30\n\nButton A: X+10, Y+20\nButton B: X+20, Y+10\nPrize: X=3030, Y=3030\n").unwrap();
+    assert_eq!(day.part_1(), Answer::from(0));
+}
+
+#[test]
+fn day14() {
+    // the example bathroom is only 11 wide and 7 tall, and has no christmas tree to find
+    let day = example::<day14::Day14>("day14.txt").with_size(11, 7).unwrap();
+    assert_eq!(day.part_1(), Answer::from(12));
+}
+
+#[test]
+fn day15() {
+    let day: day15::Day15 = example("day15.txt");
+    assert_eq!(day.part_1(), Answer::from(10092));
+    assert_eq!(day.part_2(), Answer::from(9021));
+}
+
+#[test]
+fn day16() {
+    let first: day16::Day16 = example("day16.txt");
+    assert_eq!(first.part_1(), Answer::from(7036));
+    assert_eq!(first.part_2(), Answer::from(45));
+
+    let second: day16::Day16 = example("day16-second.txt");
+    assert_eq!(second.part_1(), Answer::from(11048));
+    assert_eq!(second.part_2(), Answer::from(64));
+}
+
+#[test]
+fn day17() {
+    // part 2 has its own example, the part 1 program never outputs a copy of itself
+    let day: day17::Day17 = example("day17.txt");
+    assert_eq!(day.part_1(), Answer::from("4,6,3,5,6,3,5,2,1,0"));
+
+    let quine: day17::Day17 = example("day17-quine.txt");
+    assert_eq!(quine.part_2(), Answer::from(117440));
+}
+
+#[test]
+fn day18() {
+    // the example memory space is 7x7 and part 1 only lets 12 bytes fall
+    let day = example::<day18::Day18>("day18.txt").with_size(7).with_fallen(12);
+    assert_eq!(day.part_1(), Answer::from(22));
+    assert_eq!(day.part_2(), Answer::Coordinate(6, 1));
+}
+
+#[test]
+fn day19() {
+    let day: day19::Day19 = example("day19.txt");
+    assert_eq!(day.part_1(), Answer::from(6));
+    assert_eq!(day.part_2(), Answer::from(16));
+}
+
+#[test]
+fn day20() {
+    // none of the example cheats save 100 picoseconds, so use thresholds the puzzle lists counts for
+    let day = |threshold| example::<day20::Day20>("day20.txt").with_threshold(threshold);
+    assert_eq!(day(64).part_1(), Answer::from(1));
+    assert_eq!(day(20).part_1(), Answer::from(5));
+    assert_eq!(day(50).part_2(), Answer::from(285));
+    assert_eq!(day(76).part_2(), Answer::from(3));
+}
+
+#[test]
+fn day21() {
+    let day: day21::Day21 = example("day21.txt");
+    assert_eq!(day.part_1(), Answer::from(126384));
+}
+
+#[test]
+fn day22() {
+    let day: day22::Day22 = example("day22.txt");
+    assert_eq!(day.part_1(), Answer::from(37327623));
+
+    let bananas: day22::Day22 = example("day22-bananas.txt");
+    assert_eq!(bananas.part_2(), Answer::from(23));
+}
+
+#[test]
+fn day23() {
+    let day: day23::Day23 = example("day23.txt");
+    assert_eq!(day.part_1(), Answer::from(7));
+    assert_eq!(day.part_2(), Answer::from("co,de,ka,ta"));
+}
+
+#[test]
+fn day24() {
+    // the part 2 example swaps wires in an AND circuit rather than an adder, so only part 1 applies
+    let day: day24::Day24 = example("day24.txt");
+    assert_eq!(day.part_1(), Answer::from(4));
+    // the gates are AND, XOR and OR on each pair of bits
+    let day = day.with_inputs(0b011, 0b001);
+    assert_eq!(day.part_1(), Answer::from(3));
+    assert!(day.evaluate()["z01"]);
+}
+
+#[test]
+fn day25() {
+    let day: day25::Day25 = example("day25.txt");
+    assert_eq!(day.part_1(), Answer::from(3));
+}
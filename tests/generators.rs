@@ -0,0 +1,27 @@
+// every generator has to write inputs the days can parse and solve, at a size that keeps this quick
+use advent_of_code_2024::{generator, solver};
+use util::random::check;
+
+#[test]
+fn generated_inputs_solve() {
+    for day in 1..=25 {
+        let (generate, size) = generator(day).unwrap();
+        check(3, |rng| {
+            let input = generate(rng, size.min(20));
+            let run = solver(day).unwrap()(&input, None).map_err(|why| format!("Day {}: {}\n{}", day, why, input))?;
+            if run.answers.iter().any(|it| it.is_none()) {
+                return Err(format!("Day {} didn't answer both parts for\n{}", day, input));
+            }
+            return Ok(());
+        });
+    }
+}
+
+#[test]
+fn the_same_seed_gives_the_same_input() {
+    for day in 1..=25 {
+        let (generate, size) = generator(day).unwrap();
+        let input = generate(&mut util::random::Rng::new(42), size.min(20));
+        assert_eq!(input, generate(&mut util::random::Rng::new(42), size.min(20)), "Day {}", day);
+    }
+}
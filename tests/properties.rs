@@ -0,0 +1,177 @@
+// days with clever shortcuts checked against slow but obvious versions on small random inputs
+// a failure names the seed, AOC_SEED=<seed> cargo test --test properties reruns just that input
+use std::collections::{HashSet, VecDeque};
+
+use advent_of_code_2024::*;
+use util::random::check;
+use util::{Answer, Solution};
+
+fn parse<S: Solution>(input: &str) -> S {
+    return S::parse(input).unwrap_or_else(|why| panic!("Generated an invalid input: {}\n{}", why, input));
+}
+
+fn compare(input: &str, got: Answer, expected: Answer) -> Result<(), String> {
+    if got == expected {
+        return Ok(());
+    }
+    return Err(format!("Got {} but brute force found {} for\n{}", got, expected, input));
+}
+
+fn numbers(text: &str) -> Vec<i64> {
+    return text.split(|it: char| !it.is_ascii_digit()).filter(|it| !it.is_empty()).map(|it| it.parse().unwrap()).collect();
+}
+
+#[test]
+fn day11_counts_match_blinking_every_stone() {
+    check(10, |rng| {
+        let input = day11::generate(rng, 3);
+        let mut stones: Vec<i64> = numbers(&input);
+        for _ in 0..25 {
+            stones = stones.into_iter().flat_map(|stone| {
+                let digits = stone.to_string();
+                return match stone {
+                    0 => vec![1],
+                    _ if digits.len().is_multiple_of(2) => vec![numbers(&digits[..digits.len() / 2])[0], numbers(&digits[digits.len() / 2..])[0]],
+                    _ => vec![stone * 2024],
+                };
+            }).collect();
+        }
+        return compare(&input, parse::<day11::Day11>(&input).part_1(), Answer::from(stones.len()));
+    });
+}
+
+#[test]
+fn day13_tokens_match_trying_every_press() {
+    check(200, |rng| {
+        let input = day13::generate(rng, 5);
+        let mut tokens = 0;
+        for machine in input.split("\n\n") {
+            let [a_x, a_y, b_x, b_y, prize_x, prize_y] = numbers(machine)[..] else { unreachable!() };
+            // each button is pressed at most 100 times in part 1
+            let cheapest = (0..=100).flat_map(|a| (0..=100).map(move |b| (a, b)))
+                .filter(|(a, b)| a * a_x + b * b_x == prize_x && a * a_y + b * b_y == prize_y)
+                .map(|(a, b)| a * 3 + b)
+                .min();
+            tokens += cheapest.unwrap_or(0);
+        }
+        return compare(&input, parse::<day13::Day13>(&input).part_1(), Answer::from(tokens));
+    });
+}
+
+// straightforward interpreter for the day 17 computer
+fn run(a: i64, program: &[i64]) -> Vec<i64> {
+    let mut registers = [a, 0, 0];
+    let mut output: Vec<i64> = vec![];
+    let mut pointer = 0;
+    while pointer + 1 < program.len() {
+        let (opcode, operand) = (program[pointer], program[pointer + 1]);
+        // 7 is only ever used as a literal operand
+        let combo = if operand < 4 { operand } else { registers.get(operand as usize - 4).copied().unwrap_or(7) };
+        pointer += 2;
+        match opcode {
+            0 => registers[0] >>= combo,
+            1 => registers[1] ^= operand,
+            2 => registers[1] = combo % 8,
+            3 if registers[0] != 0 => pointer = operand as usize,
+            3 => {},
+            4 => registers[1] ^= registers[2],
+            5 => output.push(combo % 8),
+            6 => registers[1] = registers[0] >> combo,
+            _ => registers[2] = registers[0] >> combo,
+        }
+    }
+    return output;
+}
+
+#[test]
+fn day17_search_matches_trying_every_register() {
+    check(20, |rng| {
+        // a up to 8^4 keeps counting up from 0 quick
+        let outputs = 1 + rng.below(4);
+        let input = day17::generate(rng, outputs);
+        let numbers = numbers(&input);
+        let (a, program) = (numbers[0], &numbers[3..]);
+        let target = run(a, program);
+
+        let day = parse::<day17::Day17>(&input);
+        let output = target.iter().map(|it| it.to_string()).collect::<Vec<_>>().join(",");
+        compare(&input, day.part_1(), Answer::from(output))?;
+        // a itself gives the target, but there may be a smaller value that does too
+        let lowest = (0..=a).find(|it| run(*it, program) == target).unwrap();
+        return compare(&input, day.with_target(target).part_2(), Answer::from(lowest));
+    });
+}
+
+// fewest presses on the outermost keypad to type the code, found by searching every arm position
+fn shortest_presses(code: &str, robots: usize) -> usize {
+    const NUMERIC: [&str; 4] = ["789", "456", "123", " 0A"];
+    const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+    let key = |rows: &[&str], (x, y): (usize, usize)| rows.get(y).and_then(|row| row.as_bytes().get(x)).map(|it| *it as char);
+    let find = |rows: &[&str], key: char| {
+        return rows.iter().enumerate().find_map(|(y, row)| row.find(key).map(|x| (x, y))).unwrap();
+    };
+    let code: Vec<char> = code.chars().collect();
+
+    // arm over the numeric keypad, arms over the directional keypads, keys typed so far
+    let start = (find(&NUMERIC, 'A'), vec![find(&DIRECTIONAL, 'A'); robots], 0);
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some(((numeric, arms, typed), presses)) = queue.pop_front() {
+        if typed == code.len() {
+            return presses;
+        }
+        for pressed in ['^', 'v', '<', '>', 'A'] {
+            let (mut numeric, mut arms, mut typed) = (numeric, arms.clone(), typed);
+            // follow the press down the chain until it moves an arm or types a key
+            let mut pressed = pressed;
+            let mut level = robots;
+            let valid = loop {
+                let (rows, arm): (&[&str], &mut (usize, usize)) = if level == 0 { (&NUMERIC, &mut numeric) } else { (&DIRECTIONAL, &mut arms[level - 1]) };
+                let (x, y) = *arm;
+                let moved = match pressed {
+                    '^' => y.checked_sub(1).map(|y| (x, y)),
+                    'v' => Some((x, y + 1)),
+                    '<' => x.checked_sub(1).map(|x| (x, y)),
+                    '>' => Some((x + 1, y)),
+                    _ => None,
+                };
+                if pressed != 'A' {
+                    // arms panic if they go over a gap or off the keypad
+                    let Some(moved) = moved.filter(|it| key(rows, *it).is_some_and(|it| it != ' ')) else { break false; };
+                    *arm = moved;
+                    break true;
+                }
+                let key = key(rows, *arm).unwrap();
+                if level == 0 {
+                    if key != code[typed] { break false; }
+                    typed += 1;
+                    break true;
+                }
+                pressed = key;
+                level -= 1;
+            };
+            let next = (numeric, arms, typed);
+            if valid && seen.insert(next.clone()) {
+                queue.push_back((next, presses + 1));
+            }
+        }
+    }
+    unreachable!("Every code can be typed");
+}
+
+#[test]
+fn day21_presses_match_searching_every_keypad() {
+    check(10, |rng| {
+        let input = day21::generate(rng, 3);
+        let complexity = |robots| input.lines().map(|code| shortest_presses(code, robots) * numbers(code)[0] as usize).sum::<usize>();
+        let day = parse::<day21::Day21>(&input);
+        compare(&input, day.part_1(), Answer::from(complexity(2)))?;
+        // part 2 takes a different route to the same answer, so check it with a few robots too
+        for robots in 0..=3 {
+            let day = parse::<day21::Day21>(&input).with_robots(robots as i64);
+            compare(&input, day.part_2(), Answer::from(complexity(robots)))
+                .map_err(|why| format!("With {} robots: {}", robots, why))?;
+        }
+        return Ok(());
+    });
+}
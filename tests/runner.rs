@@ -0,0 +1,119 @@
+// drives the aoc binary against the example fixtures
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn fixtures() -> PathBuf {
+    return PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+}
+
+fn answers_file(name: &str) -> PathBuf {
+    // one file per test so they can run in parallel
+    let path = std::env::temp_dir().join(format!("aoc-{}-{}.toml", name, std::process::id()));
+    let _ = fs::remove_file(&path);
+    return path;
+}
+
+fn aoc(arguments: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_aoc")).args(arguments).arg("--input-dir").arg(fixtures()).output().unwrap();
+}
+
+#[test]
+fn recorded_answers_verify() {
+    let path = answers_file("record");
+    let answers = path.to_str().unwrap();
+
+    let record = aoc(&["run", "1..=2", "--record", "--answers", answers]);
+    assert!(record.status.success());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "[day01]\npart1 = 11\npart2 = 31\n\n[day02]\npart1 = 2\npart2 = 4\n");
+
+    let verify = aoc(&["run", "1..=3", "--verify", "--answers", answers]);
+    let output = String::from_utf8(verify.stdout).unwrap();
+    assert!(verify.status.success());
+    assert!(output.ends_with("4 passed, 0 failed, 2 missing\n"), "{}", output);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mismatches_fail() {
+    let path = answers_file("mismatch");
+    fs::write(&path, "[day01]\npart1 = 11\npart2 = 32\n").unwrap();
+
+    let verify = aoc(&["run", "1", "--verify", "--answers", path.to_str().unwrap()]);
+    let output = String::from_utf8(verify.stdout).unwrap();
+    assert_eq!(verify.status.code(), Some(1));
+    assert!(output.contains("  1 |    2 | FAIL    | got 31, expected 32\n"), "{}", output);
+    assert!(output.ends_with("1 passed, 1 failed, 0 missing\n"), "{}", output);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn bench_reports_every_step() {
+    let bench = aoc(&["bench", "1..=2", "--runs", "3", "--format", "csv"]);
+    let output = String::from_utf8(bench.stdout).unwrap();
+    assert!(bench.status.success());
+    let lines: Vec<&str> = output.lines().collect();
+    // built with alloc-stats there are allocation columns after the timings
+    assert!(lines[0].starts_with("day,step,runs,min_ns,median_ns,max_ns"), "{}", output);
+    // parse, part 1 and part 2 for each day
+    assert_eq!(lines.len(), 7, "{}", output);
+    assert!(lines[1].starts_with("1,parse,3,"), "{}", output);
+    assert!(lines[6].starts_with("2,part 2,3,"), "{}", output);
+}
+
+#[test]
+fn json_lines() {
+    let run = aoc(&["run", "1..=2", "--part", "2", "--format", "json"]);
+    let output = String::from_utf8(run.stdout).unwrap();
+    assert!(run.status.success());
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 2, "{}", output);
+    assert!(lines[0].starts_with(r#"{"day":1,"part":2,"answer":31,"type":"integer","elapsed_ns":"#), "{}", output);
+    assert!(lines[1].starts_with(r#"{"day":2,"part":2,"answer":4,"type":"integer","elapsed_ns":"#), "{}", output);
+
+    // a missing input is reported in place of the answers
+    let missing = Command::new(env!("CARGO_BIN_EXE_aoc")).args(["run", "1", "--format", "json", "--input-dir", "does-not-exist"]).output().unwrap();
+    assert_eq!(missing.status.code(), Some(1));
+    assert!(String::from_utf8(missing.stdout).unwrap().starts_with(r#"{"day":1,"error":""#));
+}
+
+#[test]
+fn jobs_keep_days_in_order() {
+    let sequential = aoc(&["run", "1..=12", "--format", "json"]);
+    let parallel = aoc(&["run", "1..=12", "--format", "json", "--jobs", "4"]);
+    assert!(parallel.status.success());
+    // the same answers in the same order, only the timings differ
+    let answers = |output: &Output| String::from_utf8(output.stdout.clone()).unwrap().lines()
+        .map(|line| line.split(",\"elapsed_ns\"").next().unwrap().to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(answers(&parallel), answers(&sequential));
+    assert_eq!(answers(&parallel).len(), 24);
+    assert!(!aoc(&["run", "1..=2", "--jobs", "0"]).status.success());
+}
+
+#[test]
+fn unsolved_parts_fail_and_are_not_recorded() {
+    // jumps back to the start forever without outputting anything, so neither part has an answer
+    let input = std::env::temp_dir().join(format!("aoc-unsolved-{}.txt", std::process::id()));
+    fs::write(&input, "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n").unwrap();
+    let (input, path) = (input.to_str().unwrap(), answers_file("unsolved"));
+    let answers = path.to_str().unwrap();
+
+    let record = aoc(&["run", "17", "--input", input, "--record", "--answers", answers]);
+    let errors = String::from_utf8(record.stderr).unwrap();
+    assert_eq!(record.status.code(), Some(1));
+    assert!(errors.contains("Day 17 part 1: Stopped after 1000000 instructions\n"), "{}", errors);
+    assert!(!fs::read_to_string(&path).unwrap().contains("day17"));
+
+    let verify = aoc(&["run", "17", "--input", input, "--verify", "--answers", answers]);
+    let output = String::from_utf8(verify.stdout).unwrap();
+    assert_eq!(verify.status.code(), Some(1));
+    assert!(output.contains(" 17 |    2 | error   | see below\n"), "{}", output);
+
+    let json = aoc(&["run", "17", "--input", input, "--part", "2", "--format", "json"]);
+    let output = String::from_utf8(json.stdout).unwrap();
+    assert_eq!(json.status.code(), Some(1));
+    assert!(output.starts_with("{\"day\":17,\"part\":2,\"error\":\"Can't search this program: expected one out in the loop\",\"elapsed_ns\":"), "{}", output);
+    fs::remove_file(input).unwrap();
+    fs::remove_file(&path).unwrap();
+}
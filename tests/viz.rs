@@ -0,0 +1,36 @@
+// every day with frames can draw its example, from the first frame to the last
+use std::fs;
+
+use advent_of_code_2024::visualizer;
+
+#[test]
+fn examples_have_frames() {
+    for day in [6, 10, 12, 14, 15, 16, 18, 20] {
+        let path = format!("{}/tests/fixtures/day{:02}.txt", env!("CARGO_MANIFEST_DIR"), day);
+        let input = fs::read_to_string(&path).unwrap();
+        let frames: Vec<String> = visualizer(day).unwrap()(&input).unwrap().map(|it| it.render(false)).collect();
+        assert!(!frames.is_empty(), "Day {} has no frames", day);
+    }
+    assert!(visualizer(1).is_none());
+}
+
+#[test]
+fn guard_patrol_ends_with_the_tiles_visited() {
+    let input = fs::read_to_string(format!("{}/tests/fixtures/day06.txt", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    let last = visualizer(6).unwrap()(&input).unwrap().last().unwrap();
+    // the same 41 tiles as part 1 of the example, plus the guard on the way out
+    let rendered = last.render(false);
+    assert_eq!(rendered.matches('X').count() + rendered.matches('*').count(), 41);
+    assert!(rendered.ends_with("41 tiles visited\n"));
+}
+
+#[test]
+fn guard_stuck_in_a_loop_still_ends() {
+    // the guard walks up, right, down and left around the four walls forever
+    let input = ".#....\n.....#\n.^....\n#.....\n....#.\n";
+    let frames: Vec<String> = visualizer(6).unwrap()(input).unwrap().map(|it| it.render(false)).collect();
+    let last = frames.last().unwrap();
+    assert!(last.ends_with("10 tiles visited, the guard is stuck in a loop\n"), "{}", last);
+    // every tile of the loop is highlighted
+    assert_eq!(last.matches('*').count(), 10, "{}", last);
+}
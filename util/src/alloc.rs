@@ -0,0 +1,81 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+// totals since the program started, nothing counts until CountingAllocator is the global allocator
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+// highest CURRENT_BYTES has been since measure last reset it
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+// the system allocator with counters on top, the aoc crate installs it with the alloc-stats feature
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc(layout) };
+        if !pointer.is_null() {
+            grow(layout.size());
+        }
+        return pointer;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc_zeroed(layout) };
+        if !pointer.is_null() {
+            grow(layout.size());
+        }
+        return pointer;
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(pointer, layout) };
+        CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    // a vector growing is an allocation as far as the counts go, it's often a copy too
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let moved = unsafe { System.realloc(pointer, layout, new_size) };
+        if !moved.is_null() {
+            CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+            grow(new_size);
+        }
+        return moved;
+    }
+}
+
+fn grow(bytes: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    let current = CURRENT_BYTES.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+// what a piece of code asked of the allocator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Usage {
+    // allocations and reallocations
+    pub allocations: u64,
+    // the most memory it held on to at once, on top of what was already allocated before it started
+    pub peak_bytes: usize,
+}
+
+// whether CountingAllocator is the global allocator, which the alloc-stats feature promises
+pub fn is_counting() -> bool {
+    return cfg!(feature = "alloc-stats");
+}
+
+// runs the function and reports its usage, None without the counting allocator
+// the counters are shared by every thread, so anything else running at the same time is included
+pub fn measure<T>(function: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !is_counting() {
+        return (function(), None);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = CURRENT_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(start, Ordering::Relaxed);
+    let value = function();
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed).saturating_sub(start),
+    };
+    return (value, Some(usage));
+}
@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{numbered_lines, Answer, InputError, Line};
+
+// file the accepted answers are kept in, next to the inputs they belong to
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// accepted answers keyed by day and part, stored as a small subset of TOML:
+//
+// [day01]
+// part1 = 1234
+// part2 = "abc"
+//
+// coordinates are written as a two element array, e.g. [6, 1]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<(usize, usize), Answer>,
+}
+
+impl Answers {
+    pub fn get(&self, day: usize, part: usize) -> Option<&Answer> {
+        return self.entries.get(&(day, part));
+    }
+
+    // a part that wasn't solved has nothing to record, so it's left as it was
+    pub fn set(&mut self, day: usize, part: usize, answer: Answer) {
+        if answer.is_solved() {
+            self.entries.insert((day, part), answer);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.entries.is_empty();
+    }
+
+    pub fn parse(input: &str) -> Result<Self, InputError> {
+        let mut answers = Answers::default();
+        let mut day: Option<usize> = None;
+        for line in numbered_lines(input) {
+            // comments and blank lines don't matter, comments only get a line of their own
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') { continue; }
+
+            if let Some(table) = text.strip_prefix('[') {
+                let table = table.strip_suffix(']').ok_or_else(|| line.error(text, "Expected a table like [day01]"))?;
+                let number = line.strip_prefix(table, "day")?;
+                day = Some(line.parse(number)?);
+                continue;
+            }
+
+            let day = day.ok_or_else(|| InputError::on_line(line.number, "Expected a [dayNN] table before the first answer"))?;
+            let (key, value) = line.split_once(text, "=")?;
+            let part: usize = line.parse(line.strip_prefix(key.trim(), "part")?)?;
+            if part != 1 && part != 2 {
+                return Err(line.error(key, format!("Expected part1 or part2 but found '{}'", key.trim())));
+            }
+            answers.set(day, part, parse_value(&line, value.trim())?);
+        }
+        return Ok(answers);
+    }
+
+    // a missing file just means nothing has been recorded yet
+    pub fn load(path: &Path) -> Result<Self, InputError> {
+        return match fs::read_to_string(path) {
+            Ok(input) => Answers::parse(&input).map_err(|why| why.with_path(path)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(InputError::Io { path: path.to_path_buf(), error }),
+        };
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        return fs::write(path, self.to_string());
+    }
+}
+
+fn parse_value(line: &Line, value: &str) -> Result<Answer, InputError> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let text = quoted.strip_suffix('"').ok_or_else(|| line.error(value, "Expected a closing '\"'"))?;
+        // only quotes and backslashes get escaped when writing
+        let mut unescaped = String::new();
+        let mut characters = text.chars();
+        while let Some(character) = characters.next() {
+            unescaped.push(if character == '\\' { characters.next().unwrap_or('\\') } else { character });
+        }
+        return Ok(Answer::String(unescaped));
+    }
+    if let Some(array) = value.strip_prefix('[') {
+        let array = array.strip_suffix(']').ok_or_else(|| line.error(value, "Expected a closing ']'"))?;
+        let (x, y) = line.split_once(array, ",")?;
+        return Ok(Answer::Coordinate(line.parse(x.trim())?, line.parse(y.trim())?));
+    }
+    return Ok(Answer::Integer(line.parse(value)?));
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut current: Option<usize> = None;
+        for ((day, part), answer) in &self.entries {
+            if current != Some(*day) {
+                // blank line between days
+                if current.is_some() { writeln!(f)?; }
+                writeln!(f, "[day{:02}]", day)?;
+                current = Some(*day);
+            }
+            match answer {
+                Answer::Integer(value) => writeln!(f, "part{} = {}", part, value)?,
+                Answer::String(value) => writeln!(f, "part{} = \"{}\"", part, value.replace('\\', "\\\\").replace('"', "\\\""))?,
+                Answer::Coordinate(x, y) => writeln!(f, "part{} = [{}, {}]", part, x, y)?,
+                // set never keeps these
+                Answer::Unsolved(_) => {},
+            }
+        }
+        return Ok(());
+    }
+}
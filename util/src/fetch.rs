@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::input_file;
+
+// session cookie of a logged in account, every account gets its own inputs
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+// lets tests and mirrors stand in for the real site
+pub const BASE_URL_VARIABLE: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+// kept next to the inputs, in the same format as sha256sum but with a 64 bit FNV-1a hash
+pub const CHECKSUMS_FILE: &str = "checksums.txt";
+// the site asks automated tools to say who they are
+const USER_AGENT: &str = "advent-of-code-2024 input cache";
+
+// everything that can go wrong while downloading or checking an input
+#[derive(Debug)]
+pub enum FetchError {
+    // downloading needs a session cookie
+    NoSession,
+    // the server answered, but not with an input
+    Status { url: String, status: u16, body: String },
+    // the server couldn't be reached at all
+    Transport { url: String, message: String },
+    // the cached input isn't what was downloaded
+    Checksum { path: PathBuf, expected: String, found: String },
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            FetchError::NoSession => write!(f, "Set {} to the session cookie of your account to download inputs", SESSION_VARIABLE),
+            // the site explains what's wrong in the first line, e.g. that the puzzle isn't unlocked yet
+            FetchError::Status { url, status, body } => write!(f, "{} returned {}: {}", url, status, body.lines().next().unwrap_or("")),
+            FetchError::Transport { url, message } => write!(f, "Failed to reach {}: {}", url, message),
+            FetchError::Checksum { path, expected, found } => write!(
+                f, "{} changed since it was downloaded (checksum {}, expected {}), delete it to download it again",
+                path.display(), found, expected,
+            ),
+            FetchError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+        };
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            FetchError::Io { error, .. } => Some(error),
+            _ => None,
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    // already on disk, the server wasn't contacted
+    Cached,
+}
+
+// downloads inputs into the input directory, once
+pub struct InputCache {
+    dir: PathBuf,
+    year: u32,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl InputCache {
+    // the base url and session come from the environment, see the with_* methods to override them
+    pub fn new(dir: &Path, year: u32) -> Self {
+        return InputCache {
+            dir: dir.to_path_buf(),
+            year,
+            base_url: std::env::var(BASE_URL_VARIABLE).unwrap_or(DEFAULT_BASE_URL.to_owned()),
+            session: std::env::var(SESSION_VARIABLE).ok().filter(|it| !it.trim().is_empty()),
+        };
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        return self;
+    }
+
+    pub fn with_session(mut self, session: Option<String>) -> Self {
+        self.session = session;
+        return self;
+    }
+
+    pub fn path(&self, day: usize) -> PathBuf {
+        return self.dir.join(input_file(day));
+    }
+
+    pub fn url(&self, day: usize) -> String {
+        return format!("{}/{}/day/{}/input", self.base_url.trim_end_matches('/'), self.year, day);
+    }
+
+    // an input that is already there is checked but never downloaded again
+    pub fn fetch(&self, day: usize) -> Result<Fetched, FetchError> {
+        if self.verify(day)? {
+            return Ok(Fetched::Cached);
+        }
+        let session = self.session.as_ref().ok_or(FetchError::NoSession)?;
+        let input = self.download(&self.url(day), session)?;
+
+        fs::create_dir_all(&self.dir).map_err(|error| FetchError::Io { path: self.dir.clone(), error })?;
+        // the checksum goes first, so being stopped in between leaves no input and it's downloaded
+        // again next time, rather than an input without a checksum
+        let mut checksums = self.checksums()?;
+        checksums.insert(input_file(day), checksum(&input));
+        self.save_checksums(&checksums)?;
+        write_atomically(&self.path(day), &input)?;
+        return Ok(Fetched::Downloaded);
+    }
+
+    // false if the input isn't there yet, inputs put there by hand have no checksum and are trusted
+    pub fn verify(&self, day: usize) -> Result<bool, FetchError> {
+        let path = self.path(day);
+        let input = match fs::read(&path) {
+            Ok(input) => input,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(false),
+            Err(error) => return Err(FetchError::Io { path, error }),
+        };
+        if let Some(expected) = self.checksums()?.remove(&input_file(day)) {
+            let found = checksum(&input);
+            if found != expected {
+                return Err(FetchError::Checksum { path, expected, found });
+            }
+        }
+        return Ok(true);
+    }
+
+    fn download(&self, url: &str, session: &str) -> Result<Vec<u8>, FetchError> {
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={}", session.trim()))
+            .set("User-Agent", USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .call();
+        return match response {
+            Ok(response) => {
+                let mut input: Vec<u8> = vec![];
+                response.into_reader().read_to_end(&mut input)
+                    .map_err(|error| FetchError::Transport { url: url.to_owned(), message: error.to_string() })?;
+                Ok(input)
+            },
+            Err(ureq::Error::Status(status, response)) => {
+                Err(FetchError::Status { url: url.to_owned(), status, body: response.into_string().unwrap_or_default() })
+            },
+            Err(ureq::Error::Transport(transport)) => {
+                Err(FetchError::Transport { url: url.to_owned(), message: transport.to_string() })
+            },
+        };
+    }
+
+    // file name to checksum
+    fn checksums(&self) -> Result<BTreeMap<String, String>, FetchError> {
+        let path = self.dir.join(CHECKSUMS_FILE);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(error) => return Err(FetchError::Io { path, error }),
+        };
+        return Ok(text.lines()
+            .filter_map(|line| line.split_once("  "))
+            .map(|(checksum, name)| (name.to_owned(), checksum.to_owned()))
+            .collect());
+    }
+
+    fn save_checksums(&self, checksums: &BTreeMap<String, String>) -> Result<(), FetchError> {
+        let path = self.dir.join(CHECKSUMS_FILE);
+        let text: String = checksums.iter().map(|(name, checksum)| format!("{}  {}\n", checksum, name)).collect();
+        return write_atomically(&path, text.as_bytes());
+    }
+}
+
+// writes a file next to the real one then renames it over the top, so a download that is stopped
+// part way never leaves a truncated file behind
+fn write_atomically(path: &Path, contents: &[u8]) -> Result<(), FetchError> {
+    let name = path.file_name().map(|it| it.to_string_lossy().into_owned()).unwrap_or_default();
+    let temporary = path.with_file_name(format!(".{}.{}.tmp", name, std::process::id()));
+    let result = fs::write(&temporary, contents).and_then(|_| fs::rename(&temporary, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temporary);
+    }
+    return result.map_err(|error| FetchError::Io { path: path.to_path_buf(), error });
+}
+
+// FNV-1a, not cryptographic but plenty to notice an edited or truncated input
+pub fn checksum(bytes: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    return format!("{:016x}", hash);
+}
@@ -0,0 +1,181 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Direction, Point};
+
+// a rectangular grid stored row by row in one flat vector
+// positions are (x, y) with (0, 0) in the top left corner and y growing downwards
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        return Grid { width, height, cells: vec![fill; width * height] };
+    }
+
+    // cells are given row by row, so there has to be exactly width * height of them
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Expected {} cells for a {}x{} grid", width * height, width, height);
+        return Grid { width, height, cells };
+    }
+
+    // returns None if the rows aren't all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map(|it| it.len()).unwrap_or(0);
+        if rows.iter().any(|it| it.len() != width) {
+            return None;
+        }
+        return Some(Grid { width, height, cells: rows.into_iter().flatten().collect() });
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        return self.index_of(point).is_some();
+    }
+
+    // signed so callers can step off the edge and just get None back
+    pub fn get(&self, point: Point) -> Option<&T> {
+        return self.index_of(point).map(|index| &self.cells[index]);
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        return self.index_of(point).map(|index| &mut self.cells[index]);
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let (x, y) = point.to_index()?;
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        return Some(y * self.width + x);
+    }
+
+    // every position in reading order, left to right then top to bottom
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        return (0..self.cells.len()).map(move |index| (index % width, index / width));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    pub fn find(&self, value: &T) -> Option<(usize, usize)> where T: PartialEq {
+        return self.positions_of(value).next();
+    }
+
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a where T: PartialEq {
+        return self.iter().filter(move |(_, it)| *it == value).map(|(position, _)| position);
+    }
+
+    // orthogonal neighbors that are on the grid, clockwise starting from up
+    // the ones off the edge are skipped, so pair them up with Direction::ALL yourself if the direction matters
+    pub fn neighbors(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return self.offsets(position, Direction::ALL.map(|it| it.offset()));
+    }
+
+    // orthogonal and diagonal neighbors that are on the grid, clockwise starting from up
+    pub fn neighbors_8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let offsets = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+        return self.offsets(position, offsets.map(|(x, y)| Point::new(x, y)));
+    }
+
+    fn offsets<const N: usize>(&self, position: (usize, usize), offsets: [Point; N]) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return offsets.into_iter()
+            .map(move |offset| Point::from(position) + offset)
+            .filter(|it| self.contains(*it))
+            .map(|it| it.to_index().unwrap());
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a zero size, an empty grid has no rows anyway
+        return self.cells.chunks(self.width.max(1));
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside a grid of width {}", x, self.width);
+        return self.cells.iter().skip(x).step_by(self.width);
+    }
+
+    pub fn map<U>(&self, function: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid { width: self.width, height: self.height, cells: self.cells.iter().map(function).collect() };
+    }
+
+    // flip over the top left to bottom right diagonal, so rows become columns
+    pub fn transpose(&self) -> Self where T: Clone {
+        let cells = (0..self.width).flat_map(|x| self.column(x).cloned()).collect();
+        return Grid { width: self.height, height: self.width, cells };
+    }
+
+    pub fn rotate_clockwise(&self) -> Self where T: Clone {
+        // the first column read bottom to top becomes the first row
+        let cells = (0..self.width).flat_map(|x| self.column(x).rev().cloned()).collect();
+        return Grid { width: self.height, height: self.width, cells };
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self where T: Clone {
+        // the last column read top to bottom becomes the first row
+        let cells = (0..self.width).rev().flat_map(|x| self.column(x).cloned()).collect();
+        return Grid { width: self.height, height: self.width, cells };
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        assert!(position.0 < self.width && position.1 < self.height, "{:?} is outside a {}x{} grid", position, self.width, self.height);
+        return &self.cells[position.1 * self.width + position.0];
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        assert!(position.0 < self.width && position.1 < self.height, "{:?} is outside a {}x{} grid", position, self.width, self.height);
+        return &mut self.cells[position.1 * self.width + position.0];
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        return self.get(point).unwrap_or_else(|| panic!("{} is outside a {}x{} grid", point, self.width, self.height));
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        return self.get_mut(point).unwrap_or_else(|| panic!("{} is outside a {}x{} grid", point, width, height));
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    // prints the grid the same way it appears in a puzzle input
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 { writeln!(f)?; }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::viz::{Frame, Overlay};
+use crate::Point;
+
+// pixels each grid cell becomes along both sides, small grids are unreadable at 1
+pub const DEFAULT_SCALE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    // binary PPM (P6), about the simplest image format there is
+    Ppm,
+    Png,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        return match self {
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+        };
+    }
+
+    // picked from the extension of a file name, None for anything else
+    pub fn from_path(path: &Path) -> Option<Self> {
+        return match path.extension()?.to_str()? {
+            "ppm" => Some(Format::Ppm),
+            "png" => Some(Format::Png),
+            _ => None,
+        };
+    }
+}
+
+// an RGB image stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: [u8; 3]) -> Self {
+        return Image { width, height, pixels: vec![fill; width * height] };
+    }
+
+    // every cell becomes a square of scale pixels, colored by what's in it and the overlays on top
+    pub fn from_frame(frame: &Frame, scale: usize) -> Self {
+        let grid = frame.grid();
+        let scale = scale.max(1);
+        // later overlays are drawn over earlier ones, the same as in the terminal
+        let mut marks: HashMap<Point, Vec<Overlay>> = HashMap::new();
+        for (overlay, cells) in frame.overlays() {
+            for cell in cells {
+                marks.entry(*cell).or_default().push(*overlay);
+            }
+        }
+
+        let mut image = Image::new(grid.width() * scale, grid.height() * scale, [0, 0, 0]);
+        for (position, cell) in grid.iter() {
+            let mut color = cell_color(*cell);
+            for overlay in marks.get(&Point::from(position)).into_iter().flatten() {
+                color = overlay_color(color, *overlay);
+            }
+            for y in position.1 * scale..(position.1 + 1) * scale {
+                for x in position.0 * scale..(position.0 + 1) * scale {
+                    image.set(x, y, color);
+                }
+            }
+        }
+        return image;
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> [u8; 3] {
+        return self.pixels[y * self.width + x];
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: [u8; 3]) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut output = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        output.extend(self.pixels.iter().flatten());
+        return output;
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        // each row starts with the filter it uses, always none here since the compression copes
+        let mut raw: Vec<u8> = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+
+        let mut header: Vec<u8> = vec![];
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel, RGB, deflate, no filtering beyond per row, not interlaced
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut output: Vec<u8> = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        write_chunk(&mut output, b"IHDR", &header);
+        write_chunk(&mut output, b"IDAT", &zlib(&raw, self.width * 3 + 1));
+        write_chunk(&mut output, b"IEND", &[]);
+        return output;
+    }
+
+    pub fn encode(&self, format: Format) -> Vec<u8> {
+        return match format {
+            Format::Ppm => self.to_ppm(),
+            Format::Png => self.to_png(),
+        };
+    }
+
+    // the format comes from the extension, anything other than .ppm is written as a PNG
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        return fs::write(path, self.encode(Format::from_path(path).unwrap_or(Format::Png)));
+    }
+}
+
+// writes the frames as dir/frame-00000.png and so on, numbered from 0 so they sort in order
+pub fn save_frames(frames: impl IntoIterator<Item = Frame>, dir: &Path, format: Format, scale: usize) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut paths: Vec<PathBuf> = vec![];
+    for (index, frame) in frames.into_iter().enumerate() {
+        let path = dir.join(format!("frame-{:05}.{}", index, format.extension()));
+        fs::write(&path, Image::from_frame(&frame, scale).encode(format))?;
+        paths.push(path);
+    }
+    return Ok(paths);
+}
+
+// colors for the characters the puzzles draw their grids with
+fn cell_color(cell: char) -> [u8; 3] {
+    return match cell {
+        '#' => [90, 90, 100],
+        '.' => [15, 15, 25],
+        // boxes, the robot and the guard
+        'O' | '[' | ']' => [160, 110, 60],
+        '@' | '^' | '>' | 'v' | '<' => [240, 240, 240],
+        'S' | 'E' => [80, 200, 120],
+        // heights get brighter as they go up
+        '0'..='9' => {
+            let level = 40 + (cell as u8 - b'0') * 22;
+            [level / 3, level, level / 2]
+        },
+        // anything else, like garden plots, gets a color of its own that's the same every time
+        _ => {
+            let hash = (cell as u32).wrapping_mul(2654435761);
+            [80 + (hash >> 24) as u8 % 160, 80 + (hash >> 16) as u8 % 160, 80 + (hash >> 8) as u8 % 160]
+        },
+    };
+}
+
+fn overlay_color(color: [u8; 3], overlay: Overlay) -> [u8; 3] {
+    return match overlay {
+        // shaded, so what's underneath still shows through
+        Overlay::Visited => [color[0] / 2, color[1] / 2, color[2] / 2 + 110],
+        Overlay::Path => [250, 210, 40],
+        Overlay::Highlight => [220, 40, 40],
+    };
+}
+
+fn write_chunk(output: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    output.extend((data.len() as u32).to_be_bytes());
+    let start = output.len();
+    output.extend(kind);
+    output.extend(data);
+    let crc = crc32(&output[start..]);
+    output.extend(crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffffffffu32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb88320 } else { crc >> 1 };
+        }
+    }
+    return !crc;
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in bytes {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    return (b << 16) | a;
+}
+
+// lengths and distances a match can have, as the first value of each code and the extra bits after it
+const LENGTH_BASES: [usize; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+const LENGTH_EXTRA: [u32; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASES: [usize; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u32; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+const MAX_DISTANCE: usize = 32768;
+
+// deflate with the fixed codes, only looking for repeats of the byte before or the row above, which
+// is where nearly all the repetition in a picture of a grid is
+fn zlib(data: &[u8], stride: usize) -> Vec<u8> {
+    let mut bits = BitWriter::default();
+    // a single final block using the fixed codes
+    bits.write(1, 1);
+    bits.write(1, 2);
+    let mut index = 0;
+    while index < data.len() {
+        let longest = [1, stride].into_iter()
+            .filter(|distance| *distance <= index && *distance <= MAX_DISTANCE)
+            .map(|distance| (match_length(data, index, distance), distance))
+            .max();
+        match longest {
+            Some((length, distance)) if length >= 3 => {
+                write_match(&mut bits, length, distance);
+                index += length;
+            },
+            _ => {
+                write_symbol(&mut bits, data[index] as usize);
+                index += 1;
+            },
+        }
+    }
+    write_symbol(&mut bits, 256);
+
+    // zlib header for deflate with a 32K window and no dictionary, then the checksum at the end
+    let mut output = vec![0x78, 0x01];
+    output.extend(bits.finish());
+    output.extend(adler32(data).to_be_bytes());
+    return output;
+}
+
+fn match_length(data: &[u8], index: usize, distance: usize) -> usize {
+    return (0..258.min(data.len() - index)).take_while(|offset| data[index + offset] == data[index + offset - distance]).count();
+}
+
+// literals and lengths use the same fixed code
+fn write_symbol(bits: &mut BitWriter, symbol: usize) {
+    let (code, length) = match symbol {
+        0..=143 => (0x30 + symbol, 8),
+        144..=255 => (0x190 + symbol - 144, 9),
+        256..=279 => (symbol - 256, 7),
+        _ => (0xc0 + symbol - 280, 8),
+    };
+    bits.write_code(code as u32, length);
+}
+
+fn write_match(bits: &mut BitWriter, length: usize, distance: usize) {
+    let code = LENGTH_BASES.iter().rposition(|base| *base <= length).unwrap();
+    write_symbol(bits, 257 + code);
+    bits.write((length - LENGTH_BASES[code]) as u32, LENGTH_EXTRA[code]);
+    let code = DISTANCE_BASES.iter().rposition(|base| *base <= distance).unwrap();
+    bits.write_code(code as u32, 5);
+    bits.write((distance - DISTANCE_BASES[code]) as u32, DISTANCE_EXTRA[code]);
+}
+
+// deflate packs bits starting from the lowest bit of each byte
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    current: u32,
+    count: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, length: u32) {
+        for bit in 0..length {
+            self.current |= ((value >> bit) & 1) << self.count;
+            self.count += 1;
+            if self.count == 8 {
+                self.bytes.push(self.current as u8);
+                self.current = 0;
+                self.count = 0;
+            }
+        }
+    }
+
+    // Huffman codes are the one thing written from their highest bit down
+    fn write_code(&mut self, code: u32, length: u32) {
+        for bit in (0..length).rev() {
+            self.write((code >> bit) & 1, 1);
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.current as u8);
+        }
+        return self.bytes;
+    }
+}
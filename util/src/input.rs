@@ -0,0 +1,124 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::Grid;
+
+// everything that can go wrong while reading or parsing a puzzle input
+#[derive(Debug)]
+pub enum InputError {
+    // the input couldn't be read at all
+    Io { path: PathBuf, error: io::Error },
+    // the input was read but doesn't look like the puzzle says it should
+    // line and column start from 1 and are left empty when the problem isn't tied to a spot
+    Malformed { path: Option<PathBuf>, line: Option<usize>, column: Option<usize>, message: String },
+}
+
+impl InputError {
+    pub fn at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        return InputError::Malformed { path: None, line: Some(line), column: Some(column), message: message.into() };
+    }
+
+    pub fn on_line(line: usize, message: impl Into<String>) -> Self {
+        return InputError::Malformed { path: None, line: Some(line), column: None, message: message.into() };
+    }
+
+    pub fn missing(message: impl Into<String>) -> Self {
+        return InputError::Malformed { path: None, line: None, column: None, message: message.into() };
+    }
+
+    // parsers only see the text, so the file name gets filled in by whoever read it
+    pub fn with_path(self, path: &Path) -> Self {
+        return match self {
+            InputError::Malformed { line, column, message, .. } => {
+                InputError::Malformed { path: Some(path.to_path_buf()), line, column, message }
+            },
+            error => error,
+        };
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            InputError::Io { path, error } => write!(f, "Failed to read {}: {}", path.display(), error),
+            InputError::Malformed { path, line, column, message } => {
+                // compiler style location, e.g. src/resources/day13.txt:4:12
+                match path {
+                    Some(path) => write!(f, "{}", path.display())?,
+                    None => write!(f, "<input>")?,
+                }
+                if let Some(line) = line { write!(f, ":{}", line)?; }
+                if let Some(column) = column { write!(f, ":{}", column)?; }
+                write!(f, ": {}", message)
+            },
+        };
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            InputError::Io { error, .. } => Some(error),
+            InputError::Malformed { .. } => None,
+        };
+    }
+}
+
+// a single line of the input that remembers where it came from so errors can point at the problem
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn column_of(&self, token: &str) -> usize {
+        // tokens are slices of the line, so their offset gives the column directly
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset > self.text.len() {
+            return 1;
+        }
+        return self.text[..offset].chars().count() + 1;
+    }
+
+    pub fn error(&self, token: &str, message: impl Into<String>) -> InputError {
+        return InputError::at(self.number, self.column_of(token), message);
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &str) -> Result<T, InputError> where T::Err: fmt::Display {
+        return token.parse().map_err(|why| self.error(token, format!("Invalid value '{}': {}", token, why)));
+    }
+
+    pub fn split_once(&self, token: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), InputError> {
+        return token.split_once(delimiter).ok_or_else(|| self.error(token, format!("Expected '{}' in '{}'", delimiter, token)));
+    }
+
+    pub fn strip_prefix(&self, token: &'a str, prefix: &str) -> Result<&'a str, InputError> {
+        return token.strip_prefix(prefix).ok_or_else(|| self.error(token, format!("Expected '{}' at the start of '{}'", prefix, token)));
+    }
+}
+
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    return input.lines().enumerate().map(|(index, text)| Line { number: index + 1, text });
+}
+
+pub fn try_parse_grid(input: &str) -> Result<Grid<char>, InputError> {
+    let mut grid: Vec<Vec<char>> = vec![];
+    for line in numbered_lines(input) {
+        let row: Vec<char> = line.text.chars().collect();
+        // every row has to be as wide as the first one
+        if let Some(first) = grid.first() {
+            if row.len() != first.len() {
+                return Err(InputError::on_line(line.number, format!("Expected {} columns but found {}", first.len(), row.len())));
+            }
+        }
+        grid.push(row);
+    }
+    if grid.is_empty() || grid[0].is_empty() {
+        return Err(InputError::missing("Expected a grid but the input is empty"));
+    }
+    return Ok(Grid::from_rows(grid).unwrap());
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use crate::{Answer, Usage};
+
+// one answer as a single line JSON object, so scripts can read results without scraping the tables
+// {"day":7,"part":1,"answer":3749,"type":"integer","elapsed_ns":41250}
+// allocations and peak_bytes are added after elapsed_ns when allocations were counted
+// a part without an answer has the error in place of the answer and its type
+// {"day":18,"part":2,"error":"The exit is never cut off","elapsed_ns":41250}
+pub fn answer_line(day: usize, part: usize, answer: &Answer, elapsed: Duration, usage: Option<Usage>) -> String {
+    let usage = usage.map(|it| format!(",\"allocations\":{},\"peak_bytes\":{}", it.allocations, it.peak_bytes)).unwrap_or_default();
+    if let Answer::Unsolved(why) = answer {
+        return format!("{{\"day\":{},\"part\":{},\"error\":{},\"elapsed_ns\":{}{}}}", day, part, quote(why), elapsed.as_nanos(), usage);
+    }
+    return format!(
+        "{{\"day\":{},\"part\":{},\"answer\":{},\"type\":\"{}\",\"elapsed_ns\":{}{}}}",
+        day, part, value(answer), answer.kind(), elapsed.as_nanos(), usage,
+    );
+}
+
+// a day that couldn't be solved, in place of its answers
+// {"day":7,"error":"src/resources/day07.txt: No such file or directory (os error 2)"}
+pub fn error_line(day: usize, message: &str) -> String {
+    return format!("{{\"day\":{},\"error\":{}}}", day, quote(message));
+}
+
+// integers stay numbers and coordinates become [x, y] so nothing has to be parsed twice
+pub fn value(answer: &Answer) -> String {
+    return match answer {
+        Answer::Integer(value) => value.to_string(),
+        Answer::String(value) => quote(value),
+        Answer::Coordinate(x, y) => format!("[{},{}]", x, y),
+        // there's no value, answer_line writes the error instead
+        Answer::Unsolved(_) => "null".to_owned(),
+    };
+}
+
+pub fn quote(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for character in text.chars() {
+        match character {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            // anything else below a space has no short escape
+            _ if character < ' ' => quoted.push_str(&format!("\\u{:04x}", character as u32)),
+            _ => quoted.push(character),
+        }
+    }
+    quoted.push('"');
+    return quoted;
+}
@@ -0,0 +1,197 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// a position or offset on a grid, x grows to the right and y grows downwards
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        return Point { x, y };
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        return (self.x - other.x).abs() + (self.y - other.y).abs();
+    }
+
+    // None when either coordinate is negative, the grid still has to check the upper bound
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        return Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?));
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from(index: (usize, usize)) -> Self {
+        return Point { x: index.0 as i64, y: index.1 as i64 };
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from(coordinates: (i64, i64)) -> Self {
+        return Point { x: coordinates.0, y: coordinates.1 };
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        return direction.offset();
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        return Point { x: self.x + other.x, y: self.y + other.y };
+    }
+}
+
+// stepping once in a direction
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        return self + direction.offset();
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        return Point { x: self.x - other.x, y: self.y - other.y };
+    }
+}
+
+impl Sub<Direction> for Point {
+    type Output = Point;
+
+    fn sub(self, direction: Direction) -> Point {
+        return self - direction.offset();
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: i64) -> Point {
+        return Point { x: self.x * scale, y: self.y * scale };
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        return Point { x: -self.x, y: -self.y };
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{},{}", self.x, self.y);
+    }
+}
+
+// one of the four ways to move on a grid
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // clockwise starting from up, so turning right is moving one along
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn offset(self) -> Point {
+        return match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        };
+    }
+
+    pub fn turn_right(self) -> Self {
+        return match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        };
+    }
+
+    pub fn turn_left(self) -> Self {
+        return match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        };
+    }
+
+    pub fn reverse(self) -> Self {
+        return self.turn_right().turn_right();
+    }
+
+    pub fn is_vertical(self) -> bool {
+        return matches!(self, Direction::Up | Direction::Down);
+    }
+
+    // the arrow puzzles use to draw this direction
+    pub fn to_char(self) -> char {
+        return match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        };
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    // hands the character back if it isn't one of ^v<>
+    fn try_from(character: char) -> Result<Self, char> {
+        return match character {
+            '^' => Ok(Direction::Up),
+            '>' => Ok(Direction::Right),
+            'v' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            _ => Err(character),
+        };
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.to_char());
+    }
+}
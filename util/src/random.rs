@@ -0,0 +1,93 @@
+use std::env;
+use std::ops::RangeInclusive;
+
+use crate::{Grid, Point};
+
+// replays a single failing case instead of running them all
+pub const SEED_VARIABLE: &str = "AOC_SEED";
+
+// small seeded generator (splitmix64), the same seed always gives the same input
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        return Rng { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        return value ^ (value >> 31);
+    }
+
+    // uniform in 0..bound, which has to be more than 0
+    pub fn below(&mut self, bound: usize) -> usize {
+        // the modulo bias is far too small to matter for test inputs
+        return (self.next_u64() % bound as u64) as usize;
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = range.end().abs_diff(*range.start()) + 1;
+        return range.start().wrapping_add((self.next_u64() % span) as i64);
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        return ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability;
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.below(items.len())];
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
+
+// a maze of one tile wide corridors with a wall all the way around, every open tile reaches every other one
+// and there is only one way between any two of them
+// corridors run along odd coordinates, so even sizes are rounded down to odd ones
+pub fn maze(rng: &mut Rng, width: usize, height: usize) -> Grid<char> {
+    let width = (width.max(3) - 1) / 2 * 2 + 1;
+    let height = (height.max(3) - 1) / 2 * 2 + 1;
+    let mut grid = Grid::new(width, height, '#');
+    // depth first, knocking down the wall to a random unvisited neighbour two tiles away
+    let mut stack: Vec<Point> = vec![Point::new(1, 1)];
+    grid[(1, 1)] = '.';
+    while let Some(current) = stack.last().copied() {
+        let mut next: Vec<Point> = [(0, -2), (2, 0), (0, 2), (-2, 0)].into_iter()
+            .map(|(x, y)| current + Point::new(x, y))
+            .filter(|it| it.x > 0 && it.y > 0 && it.x < width as i64 - 1 && it.y < height as i64 - 1 && grid[*it] == '#')
+            .collect();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        rng.shuffle(&mut next);
+        let wall = Point::new((current.x + next[0].x) / 2, (current.y + next[0].y) / 2);
+        grid[wall] = '.';
+        grid[next[0]] = '.';
+        stack.push(next[0]);
+    }
+    return grid;
+}
+
+// runs a property against inputs from differently seeded generators and names the seed that broke it
+// the property returns a description of what went wrong
+pub fn check(cases: u64, mut property: impl FnMut(&mut Rng) -> Result<(), String>) {
+    let seeds: Vec<u64> = match env::var(SEED_VARIABLE).ok().and_then(|it| it.parse().ok()) {
+        Some(seed) => vec![seed],
+        None => (0..cases).collect(),
+    };
+    for seed in seeds {
+        if let Err(why) = property(&mut Rng::new(seed)) {
+            panic!("Failed with seed {}, rerun just this case with {}={}\n{}", seed, SEED_VARIABLE, seed, why);
+        }
+    }
+}
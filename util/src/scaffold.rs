@@ -0,0 +1,244 @@
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+// the pieces every day is made of, relative to the root of a year crate
+// src/dayNN.rs            the Solution implementation
+// src/bin/dayNN.rs        a binary that runs just that day
+// src/resources/dayNN.txt the puzzle input, left empty to paste into
+// tests/fixtures/dayNN.txt the example from the puzzle text, also left empty
+// plus a module and solver registration in src/lib.rs and a test in tests/examples.rs
+//
+// returns the files that were created or changed
+pub fn new_day(root: &Path, day: usize) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("There is no day {}, days go from 1 to 25", day)));
+    }
+    let name = format!("day{:02}", day);
+    let module = root.join("src").join(format!("{}.rs", name));
+    if module.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", module.display())));
+    }
+    let krate = crate_name(root)?;
+
+    // check the registry can be updated before writing anything
+    let lib = root.join("src/lib.rs");
+    let registry = register(&read(&lib)?, day)
+        .ok_or_else(|| invalid(&lib, "Couldn't find the solver registry"))?;
+    let examples = root.join("tests/examples.rs");
+    let tests = add_test(&read(&examples)?, day);
+
+    let mut changed: Vec<PathBuf> = vec![];
+    write(&module, &day_module(day), &mut changed)?;
+    write(&root.join("src/bin").join(format!("{}.rs", name)), &day_binary(&krate, day), &mut changed)?;
+    // inputs might already be there, don't lose them
+    for directory in ["src/resources", "tests/fixtures"] {
+        let path = root.join(directory).join(format!("{}.txt", name));
+        if !path.exists() {
+            write(&path, "", &mut changed)?;
+        }
+    }
+    write(&lib, &registry, &mut changed)?;
+    write(&examples, &tests, &mut changed)?;
+    return Ok(changed);
+}
+
+// a new crate for another year, depending on the util crate at the given path
+// day 1 is added straight away so the crate builds and has something to run
+pub fn new_year(root: &Path, year: u32, util: &Path) -> io::Result<Vec<PathBuf>> {
+    let manifest = root.join("Cargo.toml");
+    if manifest.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", manifest.display())));
+    }
+    fs::create_dir_all(root)?;
+    let util = relative_path(&root.canonicalize()?, &util.canonicalize()?);
+
+    let mut changed: Vec<PathBuf> = vec![];
+    write(&manifest, &year_manifest(year, &util), &mut changed)?;
+    write(&root.join(".gitignore"), "target/\nsrc/resources/\n", &mut changed)?;
+    write(&root.join("src/lib.rs"), YEAR_LIB, &mut changed)?;
+    write(&root.join("tests/examples.rs"), &year_examples(year), &mut changed)?;
+    for path in new_day(root, 1)? {
+        // lib.rs and the examples were just written, only list them once
+        if !changed.contains(&path) {
+            changed.push(path);
+        }
+    }
+    return Ok(changed);
+}
+
+fn day_module(day: usize) -> String {
+    return format!("\
+use util::{{Answer, InputError, Solution}};
+
+pub struct Day{day:02} {{
+    lines: Vec<String>,
+}}
+
+impl Solution for Day{day:02} {{
+    fn parse(input: &str) -> Result<Self, InputError> {{
+        let lines = input.lines().map(|it| it.to_owned()).collect();
+        return Ok(Day{day:02} {{ lines }});
+    }}
+
+    fn part_1(&self) -> Answer {{
+        part_1(&self.lines).into()
+    }}
+
+    fn part_2(&self) -> Answer {{
+        part_2(&self.lines).into()
+    }}
+}}
+
+fn part_1(lines: &[String]) -> usize {{
+    return lines.len();
+}}
+
+fn part_2(lines: &[String]) -> usize {{
+    return lines.len();
+}}
+");
+}
+
+fn day_binary(krate: &str, day: usize) -> String {
+    return format!("\
+use {krate}::day{day:02}::Day{day:02};
+
+fn main() {{
+    util::run::<Day{day:02}>({day});
+}}
+");
+}
+
+fn day_test(day: usize) -> String {
+    return format!("\
+#[test]
+fn day{day:02}() {{
+    let day: day{day:02}::Day{day:02} = example(\"day{day:02}.txt\");
+    assert_eq!(day.part_1(), Answer::from(0));
+}}
+");
+}
+
+fn year_manifest(year: u32, util: &Path) -> String {
+    return format!("\
+[package]
+name = \"advent-of-code-{year}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# a workspace of its own, even when created inside another year
+[workspace]
+
+[dependencies]
+util = {{ path = \"{}\" }}
+
+[lints.clippy]
+# explicit returns are used on purpose throughout
+needless_return = \"allow\"
+", util.display());
+}
+
+// starts with a blank line to keep the modules apart from the rest
+const YEAR_LIB: &str = "
+// parses the input and runs one part, or both parts when no part is given
+pub type Solver = fn(&str, Option<usize>) -> Result<util::Run, util::InputError>;
+
+pub fn solver(day: usize) -> Option<Solver> {
+    return match day {
+        _ => None,
+    };
+}
+";
+
+fn year_examples(year: u32) -> String {
+    return format!("\
+// every day checked against the examples given in the puzzle text
+use std::fs;
+
+use advent_of_code_{year}::*;
+use util::{{Answer, Solution}};
+
+fn example<S: Solution>(name: &str) -> S {{
+    let path = format!(\"{{}}/tests/fixtures/{{}}\", env!(\"CARGO_MANIFEST_DIR\"), name);
+    let input = fs::read_to_string(&path).unwrap_or_else(|why| panic!(\"Failed to read {{}}: {{}}\", path, why));
+    return S::parse(&input).unwrap_or_else(|why| panic!(\"{{}}: {{}}\", name, why));
+}}
+");
+}
+
+// adds the module and the solver to lib.rs, keeping both in day order
+fn register(lib: &str, day: usize) -> Option<String> {
+    let mut lines: Vec<String> = lib.lines().map(|it| it.to_owned()).collect();
+
+    let arm = format!("        {} => Some(util::solve::<day{:02}::Day{:02}>),", day, day, day);
+    let fallback = lines.iter().position(|it| it.trim() == "_ => None,")?;
+    let index = (0..fallback).find(|index| registered_day(&lines[*index], " =>").is_some_and(|it| it > day)).unwrap_or(fallback);
+    lines.insert(index, arm);
+
+    // modules go after the last earlier day, or at the very top
+    let module = format!("pub mod day{:02};", day);
+    let index = lines.iter().rposition(|it| registered_day(it, ";").is_some_and(|it| it < day)).map_or(0, |it| it + 1);
+    lines.insert(index, module);
+
+    return Some(lines.join("\n") + "\n");
+}
+
+// the day number in either "pub mod day07;" or "        7 => Some(...)", None for any other line
+fn registered_day(line: &str, end: &str) -> Option<usize> {
+    let text = line.trim();
+    let number = text.strip_prefix("pub mod day").unwrap_or(text);
+    return number.split_once(end)?.0.parse().ok();
+}
+
+// puts the test before the first later day, or at the end
+fn add_test(examples: &str, day: usize) -> String {
+    let later = examples.match_indices("#[test]\nfn day").map(|(index, _)| index).find(|index| {
+        let rest = &examples[index + "#[test]\nfn day".len()..];
+        return rest.split_once('(').and_then(|(number, _)| number.parse::<usize>().ok()).is_some_and(|it| it > day);
+    });
+    return match later {
+        Some(index) => format!("{}{}\n{}", &examples[..index], day_test(day), &examples[index..]),
+        None => format!("{}\n\n{}", examples.trim_end_matches('\n'), day_test(day)),
+    };
+}
+
+// package name as it is used in code, e.g. advent_of_code_2024
+fn crate_name(root: &Path) -> io::Result<String> {
+    let manifest = root.join("Cargo.toml");
+    let text = read(&manifest)?;
+    let name = text.lines()
+        .filter_map(|line| line.trim().strip_prefix("name"))
+        .filter_map(|rest| rest.trim().strip_prefix('='))
+        .map(|value| value.trim().trim_matches('"'))
+        .next()
+        .ok_or_else(|| invalid(&manifest, "Couldn't find the package name"))?;
+    return Ok(name.replace('-', "_"));
+}
+
+// both paths have to be absolute
+fn relative_path(from: &Path, to: &Path) -> PathBuf {
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut path: PathBuf = from[common..].iter().map(|_| Component::ParentDir).collect();
+    path.extend(&to[common..]);
+    return path;
+}
+
+fn read(path: &Path) -> io::Result<String> {
+    return fs::read_to_string(path).map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path.display(), error)));
+}
+
+fn write(path: &Path, contents: &str, changed: &mut Vec<PathBuf>) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents).map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path.display(), error)))?;
+    changed.push(path.to_path_buf());
+    return Ok(());
+}
+
+fn invalid(path: &Path, message: &str) -> io::Error {
+    return io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), message));
+}
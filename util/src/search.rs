@@ -0,0 +1,184 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+// what a search found: the best cost to every node it reached and how it got there
+pub struct Search<N> {
+    pub distances: HashMap<N, usize>,
+    // every node that reaches this one with its best cost, so ties keep all of their paths
+    pub predecessors: HashMap<N, Vec<N>>,
+    // goal nodes reached with the lowest cost, empty if no goal was reached
+    pub goals: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        return Search { distances: HashMap::new(), predecessors: HashMap::new(), goals: vec![] };
+    }
+
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        return self.distances.get(node).copied();
+    }
+
+    pub fn goal(&self) -> Option<&N> {
+        return self.goals.first();
+    }
+
+    // cost of the cheapest goal, None if no goal was reached
+    pub fn goal_distance(&self) -> Option<usize> {
+        return self.goal().and_then(|it| self.distance(it));
+    }
+
+    // one best path from a start node to the given node, both ends included
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()).and_then(|it| it.first()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        return Some(path);
+    }
+
+    pub fn path(&self) -> Option<Vec<N>> {
+        return self.goal().and_then(|it| self.path_to(it));
+    }
+
+    // every node that is on at least one best path to the given node
+    pub fn on_best_paths(&self, node: &N) -> HashSet<N> {
+        let mut seen: HashSet<N> = HashSet::new();
+        if !self.distances.contains_key(node) {
+            return seen;
+        }
+        let mut to_check: Vec<&N> = vec![node];
+        while let Some(node) = to_check.pop() {
+            if !seen.insert(node.clone()) { continue; }
+            if let Some(previous) = self.predecessors.get(node) {
+                to_check.extend(previous);
+            }
+        }
+        return seen;
+    }
+
+    // returns true if this is the first time the node was reached with this cost or better
+    fn relax(&mut self, from: &N, to: N, cost: usize) -> bool {
+        match self.distances.get(&to) {
+            Some(best) if cost > *best => return false,
+            Some(best) if cost == *best => {
+                // another way to get here that is just as good, remember it but don't revisit
+                self.predecessors.entry(to).or_default().push(from.clone());
+                return false;
+            },
+            _ => {
+                self.distances.insert(to.clone(), cost);
+                self.predecessors.insert(to, vec![from.clone()]);
+                return true;
+            },
+        }
+    }
+}
+
+// breadth first search where every step costs 1
+// the search stops once every goal at the lowest distance has been found, use |_| false to map everything
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N> where N: Clone + Eq + Hash, I: IntoIterator<Item = N> {
+    let mut search = Search::new();
+    let mut queue: VecDeque<N> = VecDeque::new();
+    for start in starts {
+        search.distances.insert(start.clone(), 0);
+        queue.push_back(start);
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = search.distances[&node];
+        if search.goal_distance().is_some_and(|it| distance > it) { break; }
+        // goals are the end of the line, don't search past them
+        if is_goal(&node) {
+            search.goals.push(node);
+            continue;
+        }
+        for next in successors(&node) {
+            if search.relax(&node, next.clone(), distance + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+    return search;
+}
+
+// lowest cost search where successors come with the cost of moving to them
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N> where N: Clone + Eq + Hash, I: IntoIterator<Item = (N, usize)> {
+    return astar(starts, successors, |_| 0, is_goal);
+}
+
+// dijkstra guided by a heuristic, which must be consistent: never more than the cost of a step plus
+// the heuristic where the step goes, and 0 on goals. nodes aren't searched again once they're done,
+// so one that only never overestimates can miss a cheaper path
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N> where N: Clone + Eq + Hash, I: IntoIterator<Item = (N, usize)> {
+    let mut search = Search::new();
+    let mut queue: BinaryHeap<Entry<N>> = BinaryHeap::new();
+    let mut visited: HashSet<N> = HashSet::new();
+    for start in starts {
+        search.distances.insert(start.clone(), 0);
+        queue.push(Entry { priority: heuristic(&start), cost: 0, node: start });
+    }
+
+    while let Some(Entry { priority, cost, node }) = queue.pop() {
+        // keep going while there could still be goals that are just as cheap
+        if search.goal_distance().is_some_and(|it| priority > it) { break; }
+        // skip entries that were queued before a cheaper way to the node was found
+        if cost > search.distances[&node] || !visited.insert(node.clone()) { continue; }
+        if is_goal(&node) {
+            search.goals.push(node);
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if search.relax(&node, next.clone(), next_cost) {
+                queue.push(Entry { priority: next_cost + heuristic(&next), cost: next_cost, node: next });
+            }
+        }
+    }
+    return search;
+}
+
+struct Entry<N> {
+    priority: usize,
+    cost: usize,
+    node: N,
+}
+
+impl<N> Ord for Entry<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // flip so that the heap pops the lowest priority first
+        return other.priority.cmp(&self.priority);
+    }
+}
+
+impl<N> PartialOrd for Entry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl<N> PartialEq for Entry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.priority == other.priority;
+    }
+}
+
+impl<N> Eq for Entry<N> {}
@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+// summary of repeated timings of the same step
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    // None if there were no samples to summarise
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        // with an even number of runs take the mean of the two in the middle
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) { (sorted[middle - 1] + sorted[middle]) / 2 } else { sorted[middle] };
+        return Some(Stats { runs: sorted.len(), min: sorted[0], median, max: sorted[sorted.len() - 1] });
+    }
+}
+
+// short human readable duration with a unit that fits, e.g. 412.3 µs or 1.25 s
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+    if seconds >= 1.0 {
+        return format!("{:.2} s", seconds);
+    }
+    else if seconds >= 0.001 {
+        return format!("{:.2} ms", seconds * 1000.0);
+    }
+    return format!("{:.1} µs", seconds * 1_000_000.0);
+}
@@ -1,57 +1,299 @@
+use std::env;
+use std::fmt;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Read};
-use std::path::Path;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
 
-pub fn read_lines(filename: &str) -> Vec<String> {
-    let reader = get_reader(filename);
+pub mod alloc;
+pub mod answers;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+mod grid;
+pub mod image;
+mod input;
+pub mod json;
+mod point;
+pub mod random;
+pub mod scaffold;
+pub mod search;
+pub mod timing;
+pub mod viz;
+pub use alloc::Usage;
+pub use grid::Grid;
+pub use input::{numbered_lines, try_parse_grid, InputError, Line};
+pub use point::{Direction, Point};
+
+// environment variable that points at the directory holding the dayNN.txt files
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+// used when the variable isn't set, relative to wherever the binary is launched from
+const DEFAULT_INPUT_DIR: &str = "src/resources";
+// stands in for a file name in errors when the input was piped in
+pub const STDIN_NAME: &str = "<stdin>";
+
+// every day implements this so the runner can call into it without knowing the details
+pub trait Solution: Sized {
+    // build the puzzle state from the raw contents of the input file
+    fn parse(input: &str) -> Result<Self, InputError>;
+    fn part_1(&self) -> Answer;
+    fn part_2(&self) -> Answer;
+}
+
+// the different shapes an answer can take, so results can be compared instead of scraped from text
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    String(String),
+    Coordinate(i64, i64),
+    // the part has no answer for this input, with why not
+    // the runners report it as an error, so it's never printed, verified or recorded as an answer
+    Unsolved(String),
+}
+
+impl Answer {
+    // name of the variant, handy when the answer is written out somewhere
+    pub fn kind(&self) -> &'static str {
+        return match self {
+            Answer::Integer(_) => "integer",
+            Answer::String(_) => "string",
+            Answer::Coordinate(_, _) => "coordinate",
+            Answer::Unsolved(_) => "unsolved",
+        };
+    }
+
+    pub fn is_solved(&self) -> bool {
+        return !matches!(self, Answer::Unsolved(_));
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // format as the puzzle expects it to be submitted, pad so answers line up in tables
+        let text = match self {
+            Answer::Integer(value) => value.to_string(),
+            Answer::String(value) => value.to_owned(),
+            Answer::Coordinate(x, y) => format!("{},{}", x, y),
+            Answer::Unsolved(why) => format!("error: {}", why),
+        };
+        return f.pad(&text);
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        return Answer::Integer(value.into());
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        return Answer::Integer(value);
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        // no answer comes anywhere close to overflowing an i64
+        return Answer::Integer(value as i64);
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        return Answer::String(value);
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        return Answer::String(value.to_owned());
+    }
+}
+
+impl From<(usize, usize)> for Answer {
+    fn from(value: (usize, usize)) -> Self {
+        return Answer::Coordinate(value.0 as i64, value.1 as i64);
+    }
+}
+
+// answers from solving a day once, along with how long each step took
+pub struct Run {
+    pub answers: [Option<Answer>; 2],
+    pub parse_time: Duration,
+    // None for parts that weren't requested, same as the answers
+    pub part_times: [Option<Duration>; 2],
+    // only counted with the alloc-stats feature, None otherwise
+    pub parse_usage: Option<Usage>,
+    pub part_usage: [Option<Usage>; 2],
+}
+
+pub fn solve<S: Solution>(input: &str, part: Option<usize>) -> Result<Run, InputError> {
+    // parse once and run the requested part, or both if no part was given
+    let start = Instant::now();
+    let (solution, parse_usage) = alloc::measure(|| S::parse(input));
+    let parse_time = start.elapsed();
+    let solution = solution?;
+
+    let mut answers: [Option<Answer>; 2] = [None, None];
+    let mut part_times: [Option<Duration>; 2] = [None, None];
+    let mut part_usage: [Option<Usage>; 2] = [None, None];
+    for index in 0..2 {
+        if part.is_some_and(|it| it != index + 1) { continue; }
+        let start = Instant::now();
+        let (answer, usage) = alloc::measure(|| if index == 0 { solution.part_1() } else { solution.part_2() });
+        part_times[index] = Some(start.elapsed());
+        answers[index] = Some(answer);
+        part_usage[index] = usage;
+    }
+    return Ok(Run { answers, parse_time, part_times, parse_usage, part_usage });
+}
+
+pub fn run<S: Solution>(day: usize) {
+    // an optional argument can override where the input comes from, see input_path
+    let mut argument: Option<String> = None;
+    let mut json = false;
+    let mut arguments = env::args().skip(1);
+    while let Some(arg) = arguments.next() {
+        match arg.as_str() {
+            "--format" => json = match arguments.next().as_deref() {
+                Some("json") => true,
+                Some("text") => false,
+                _ => exit_with_usage(day),
+            },
+            _ if argument.is_none() => argument = Some(arg),
+            _ => exit_with_usage(day),
+        }
+    }
+
+    let filename = input_file(day);
+    let path = input_path(&filename, argument.as_deref());
+    let run = try_read_input(&filename, argument.as_deref())
+        .and_then(|input| solve::<S>(&input, None).map_err(|why| why.with_path(&path)));
+    match run {
+        Ok(run) => {
+            let mut unsolved = false;
+            for (index, (answer, elapsed)) in run.answers.iter().zip(run.part_times).enumerate() {
+                let (Some(answer), Some(elapsed)) = (answer, elapsed) else { continue; };
+                if json {
+                    println!("{}", json::answer_line(day, index + 1, answer, elapsed, run.part_usage[index]));
+                }
+                else if let Answer::Unsolved(why) = answer {
+                    eprintln!("Part {}: {}", index + 1, why);
+                }
+                else {
+                    println!("Part {}: {}", index + 1, answer);
+                }
+                unsolved |= !answer.is_solved();
+            }
+            if unsolved {
+                process::exit(1);
+            }
+        },
+        Err(why) => {
+            if json {
+                println!("{}", json::error_line(day, &why.to_string()));
+            }
+            eprintln!("{}", why);
+            process::exit(1);
+        },
+    }
+}
+
+fn exit_with_usage(day: usize) -> ! {
+    eprintln!("Usage: day{:02} [<file> | -] [--format text|json]", day);
+    process::exit(2);
+}
+
+// name of the input file for a day, e.g. day07.txt
+pub fn input_file(day: usize) -> String {
+    return format!("day{:02}.txt", day);
+}
+
+pub fn input_dir() -> PathBuf {
+    return match env::var_os(INPUT_DIR_VARIABLE) {
+        Some(directory) => PathBuf::from(directory),
+        None => PathBuf::from(DEFAULT_INPUT_DIR),
+    };
+}
+
+pub fn input_path(filename: &str, argument: Option<&str>) -> PathBuf {
+    // an explicit argument wins, "-" reads from stdin and anything else is a path to the input
+    return match argument {
+        Some("-") => PathBuf::from(STDIN_NAME),
+        Some(path) => PathBuf::from(path),
+        None => input_dir().join(filename),
+    };
+}
+
+pub fn try_read_input(filename: &str, argument: Option<&str>) -> Result<String, InputError> {
+    return match argument {
+        Some("-") => try_read_stdin(),
+        _ => try_read_path(&input_path(filename, argument)),
+    };
+}
+
+pub fn read_input(filename: &str, argument: Option<&str>) -> String {
+    return try_read_input(filename, argument).unwrap_or_else(|why| panic!("{}", why));
+}
+
+pub fn try_read_stdin() -> Result<String, InputError> {
+    let mut input: String = "".to_owned();
+    io::stdin().read_to_string(&mut input).map_err(|error| InputError::Io { path: PathBuf::from(STDIN_NAME), error })?;
+    return Ok(input);
+}
+
+pub fn read_stdin() -> String {
+    return try_read_stdin().unwrap_or_else(|why| panic!("{}", why));
+}
+
+pub fn try_read_lines(filename: &str) -> Result<Vec<String>, InputError> {
+    let path = input_dir().join(filename);
+    let reader = get_reader(&path)?;
     let mut lines: Vec<String> = vec![];
     for line in reader.lines() {
-        // panic if there is an error reading the line
-        lines.push(line.unwrap()); 
+        lines.push(line.map_err(|error| InputError::Io { path: path.clone(), error })?);
     }
-    return lines;
+    return Ok(lines);
+}
+
+pub fn read_lines(filename: &str) -> Vec<String> {
+    return try_read_lines(filename).unwrap_or_else(|why| panic!("{}", why));
+}
+
+pub fn try_read_file(filename: &str) -> Result<String, InputError> {
+    return try_read_path(&input_dir().join(filename));
 }
 
 pub fn read_file(filename: &str) -> String {
-    let mut reader = get_reader(filename);
+    return try_read_file(filename).unwrap_or_else(|why| panic!("{}", why));
+}
+
+pub fn try_read_path(path: &Path) -> Result<String, InputError> {
+    let mut reader = get_reader(path)?;
     let mut input: String = "".to_owned();
-    let _ = reader.read_to_string(&mut input);
-    return input;
+    reader.read_to_string(&mut input).map_err(|error| InputError::Io { path: path.to_path_buf(), error })?;
+    return Ok(input);
 }
 
-pub fn read_grid(filename: &str ) -> Vec<Vec<char>> {
-    let reader = get_reader(filename);
-    let mut grid: Vec<Vec<char>> = vec![];
-    for line in reader.lines() {
-        let mut row: Vec<char> = vec![];
-        for character in line.unwrap().chars() {
-            row.push(character);
-        }
-        grid.push(row);
-    }
-    return grid;
+pub fn read_path(path: &Path) -> String {
+    return try_read_path(path).unwrap_or_else(|why| panic!("{}", why));
 }
 
-fn get_reader(filename: &str) -> BufReader<File> {
-    let path_string: &str = &format!("src/resources/{filename}");
-    let path = Path::new(path_string);
-    // open the file and match result enum in case of error
-    let file = match File::open(path) {
-        Err(why) => panic!("Failed to open {}: {}", path.display(), why),
-        Ok(file) => file,
-    };
-    return BufReader::new(file);
+pub fn try_read_grid(filename: &str) -> Result<Grid<char>, InputError> {
+    let input = try_read_file(filename)?;
+    return try_parse_grid(&input).map_err(|why| why.with_path(&input_dir().join(filename)));
+}
+
+pub fn read_grid(filename: &str ) -> Grid<char> {
+    return try_read_grid(filename).unwrap_or_else(|why| panic!("{}", why));
 }
 
-pub fn get_points(point: (usize, usize), height: usize, width: usize) -> Vec<(usize, usize)> {
-    // get points going [up, down, left, right]
-    let list = vec![
-        (point.0 as i32, point.1 as i32 - 1), (point.0 as i32, point.1 as i32 + 1), (point.0 as i32 - 1, point.1 as i32), (point.0 as i32 + 1, point.1 as i32)
-    ];
-    // make sure each point is actually on the grid and convert to usize
-    list.into_iter().filter(|it| check_bounds(*it, height, width)).map(|it| (it.0 as usize, it.1 as usize)).collect()
+pub fn parse_grid(input: &str) -> Grid<char> {
+    return try_parse_grid(input).unwrap_or_else(|why| panic!("{}", why));
 }
 
-pub fn check_bounds(point: (i32, i32), height: usize, width: usize) -> bool {
-    point.0 >= 0 && point.0 < width as i32 && point.1 >= 0 && point.1 < height as i32
+fn get_reader(path: &Path) -> Result<BufReader<File>, InputError> {
+    let file = File::open(path).map_err(|error| InputError::Io { path: path.to_path_buf(), error })?;
+    return Ok(BufReader::new(file));
 }
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::env;
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{Grid, InputError, Point, Solution};
+
+// any value turns colors off, see https://no-color.org
+pub const NO_COLOR_VARIABLE: &str = "NO_COLOR";
+
+// moves the cursor back to the top left so each frame draws over the last one
+const HOME: &str = "\x1b[H";
+const CLEAR: &str = "\x1b[2J";
+const CLEAR_LINE: &str = "\x1b[K";
+const RESET: &str = "\x1b[0m";
+
+// days that work on a grid implement this to show how they get to their answers
+pub trait Animate: Solution {
+    fn frames(&self) -> Box<dyn Iterator<Item = Frame>>;
+}
+
+// parses the input and hands back the day's frames, the counterpart of solve for the runner
+pub fn frames<S: Animate>(input: &str) -> Result<Box<dyn Iterator<Item = Frame>>, InputError> {
+    return Ok(S::parse(input)?.frames());
+}
+
+// ways of marking cells on top of the grid, later overlays are drawn over earlier ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlay {
+    // cells that have been looked at, shaded blue
+    Visited,
+    // cells along a route, drawn in bold yellow
+    Path,
+    // cells to draw attention to, shaded red
+    Highlight,
+}
+
+impl Overlay {
+    // without colors the cell itself is replaced, with the marks the puzzle text uses where it has them
+    pub fn plain(self) -> char {
+        return match self {
+            Overlay::Visited => 'X',
+            Overlay::Path => 'O',
+            Overlay::Highlight => '*',
+        };
+    }
+}
+
+// what a cell looks like once every overlay has been applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Style {
+    // SGR parameters for the text and the shading behind it
+    foreground: Option<&'static str>,
+    background: Option<&'static str>,
+    plain: Option<char>,
+}
+
+impl Style {
+    fn apply(&mut self, overlay: Overlay) {
+        match overlay {
+            Overlay::Visited => self.background = Some("44"),
+            Overlay::Path => self.foreground = Some("1;33"),
+            Overlay::Highlight => self.background = Some("41"),
+        }
+        self.plain = Some(overlay.plain());
+    }
+
+    fn escape(&self) -> String {
+        let codes: Vec<&str> = [self.foreground, self.background].into_iter().flatten().collect();
+        return if codes.is_empty() { RESET.to_owned() } else { format!("\x1b[0;{}m", codes.join(";")) };
+    }
+}
+
+// one state of a grid with its overlays, rendered as text
+#[derive(Debug, Clone)]
+pub struct Frame {
+    grid: Grid<char>,
+    overlays: Vec<(Overlay, Vec<Point>)>,
+    caption: Option<String>,
+}
+
+impl Frame {
+    pub fn new(grid: Grid<char>) -> Self {
+        return Frame { grid, overlays: vec![], caption: None };
+    }
+
+    // returns None if the rows aren't all the same length
+    pub fn from_rows(rows: Vec<Vec<char>>) -> Option<Self> {
+        return Grid::from_rows(rows).map(Frame::new);
+    }
+
+    pub fn with_overlay<P: Into<Point>>(mut self, overlay: Overlay, cells: impl IntoIterator<Item = P>) -> Self {
+        self.overlays.push((overlay, cells.into_iter().map(Into::into).collect()));
+        return self;
+    }
+
+    pub fn with_visited<P: Into<Point>>(self, cells: impl IntoIterator<Item = P>) -> Self {
+        return self.with_overlay(Overlay::Visited, cells);
+    }
+
+    pub fn with_path<P: Into<Point>>(self, cells: impl IntoIterator<Item = P>) -> Self {
+        return self.with_overlay(Overlay::Path, cells);
+    }
+
+    pub fn with_highlights<P: Into<Point>>(self, cells: impl IntoIterator<Item = P>) -> Self {
+        return self.with_overlay(Overlay::Highlight, cells);
+    }
+
+    // a line under the grid, e.g. the step number
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        return self;
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        return &self.grid;
+    }
+
+    // in the order they were added, which is the order they're drawn in
+    pub fn overlays(&self) -> &[(Overlay, Vec<Point>)] {
+        return &self.overlays;
+    }
+
+    // every line ends in a newline, colors are ANSI escapes that are reset at the end of each row
+    pub fn render(&self, color: bool) -> String {
+        // cells off the grid are ignored so callers don't have to filter them
+        let mut styles: HashMap<Point, Style> = HashMap::new();
+        for (overlay, cells) in &self.overlays {
+            for cell in cells.iter().filter(|it| self.grid.contains(**it)) {
+                styles.entry(*cell).or_default().apply(*overlay);
+            }
+        }
+
+        let mut output = String::new();
+        for y in 0..self.grid.height() {
+            let mut current = Style::default();
+            for x in 0..self.grid.width() {
+                let style = styles.get(&Point::from((x, y))).copied().unwrap_or_default();
+                let cell = self.grid[(x, y)];
+                if !color {
+                    output.push(style.plain.unwrap_or(cell));
+                    continue;
+                }
+                // only write an escape when the style changes, big grids are slow enough to draw
+                if (style.foreground, style.background) != (current.foreground, current.background) {
+                    output.push_str(&style.escape());
+                    current = style;
+                }
+                output.push(cell);
+            }
+            if color && (current.foreground, current.background) != (None, None) {
+                output.push_str(RESET);
+            }
+            output.push('\n');
+        }
+        if let Some(caption) = &self.caption {
+            output.push_str(caption);
+            // a shorter caption mustn't leave the end of a longer one behind when drawn over it
+            if color { output.push_str(CLEAR_LINE); }
+            output.push('\n');
+        }
+        return output;
+    }
+}
+
+// plays frames one after another in the terminal, or just shows where they end up
+pub struct Animation {
+    // None to skip straight to the last frame
+    frame_time: Option<Duration>,
+    color: bool,
+}
+
+impl Animation {
+    // colors are on unless NO_COLOR is set
+    pub fn new(frames_per_second: f64) -> Self {
+        let frame_time = Duration::from_secs_f64(1.0 / frames_per_second.max(0.001));
+        return Animation { frame_time: Some(frame_time), color: env::var_os(NO_COLOR_VARIABLE).is_none() };
+    }
+
+    // only the last frame is drawn, e.g. to see the final state of a simulation
+    pub fn still() -> Self {
+        return Animation { frame_time: None, ..Animation::new(1.0) };
+    }
+
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        return self;
+    }
+
+    // returns how many frames there were
+    pub fn play(&self, frames: impl IntoIterator<Item = Frame>, output: &mut impl Write) -> io::Result<usize> {
+        let Some(frame_time) = self.frame_time else {
+            let mut count = 0;
+            let mut last: Option<Frame> = None;
+            for frame in frames {
+                count += 1;
+                last = Some(frame);
+            }
+            if let Some(frame) = last {
+                write!(output, "{}", frame.render(self.color))?;
+            }
+            return Ok(count);
+        };
+
+        let start = Instant::now();
+        let mut count = 0;
+        for frame in frames {
+            // frames are drawn on a schedule, so slow frames don't push the rest back
+            let due = start + frame_time * count as u32;
+            if let Some(wait) = due.checked_duration_since(Instant::now()) {
+                thread::sleep(wait);
+            }
+            if self.color {
+                // clear once, after that drawing over the last frame is enough and doesn't flicker
+                write!(output, "{}{}{}", if count == 0 { CLEAR } else { "" }, HOME, frame.render(true))?;
+            }
+            else {
+                // plain frames are just printed one after another with a blank line between them
+                write!(output, "{}{}", if count == 0 { "" } else { "\n" }, frame.render(false))?;
+            }
+            output.flush()?;
+            count += 1;
+        }
+        return Ok(count);
+    }
+}
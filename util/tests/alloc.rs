@@ -0,0 +1,29 @@
+use util::alloc::{self, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// the counters are shared, so everything is checked in one test rather than several running at once
+#[test]
+fn allocations_are_counted() {
+    assert!(alloc::is_counting());
+
+    let (sum, usage) = alloc::measure(|| (0..10u64).sum::<u64>());
+    assert_eq!(sum, 45);
+    assert_eq!(usage.unwrap().allocations, 0);
+
+    let (bytes, usage) = alloc::measure(|| vec![7u8; 4096]);
+    let usage = usage.unwrap();
+    assert_eq!(bytes.len(), 4096);
+    assert_eq!(usage.allocations, 1);
+    assert!(usage.peak_bytes >= 4096);
+
+    // memory freed before the end still counts towards the peak
+    let (_, usage) = alloc::measure(|| {
+        let large = vec![0u8; 1 << 20];
+        drop(large);
+        return vec![0u8; 16];
+    });
+    assert!(usage.unwrap().peak_bytes >= 1 << 20);
+    assert_eq!(usage.unwrap().allocations, 2);
+}
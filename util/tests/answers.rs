@@ -0,0 +1,48 @@
+use util::answers::Answers;
+use util::Answer;
+
+#[test]
+fn round_trips_every_kind_of_answer() {
+    let mut answers = Answers::default();
+    answers.set(1, 1, Answer::from(1834060));
+    answers.set(1, 2, Answer::from(-4));
+    answers.set(17, 1, Answer::from("4,6,3,5,6,3,5,2,1,0"));
+    answers.set(18, 2, Answer::Coordinate(6, 1));
+    answers.set(25, 2, Answer::from("say \"Merry Christmas!\" \\o/"));
+
+    let text = answers.to_string();
+    assert_eq!(Answers::parse(&text).unwrap(), answers);
+}
+
+#[test]
+fn unsolved_parts_are_never_recorded() {
+    let mut answers = Answers::default();
+    answers.set(17, 2, Answer::from(117440));
+    answers.set(17, 2, Answer::Unsolved("No value of register A outputs the target".to_owned()));
+    answers.set(18, 2, Answer::Unsolved("The exit is never cut off".to_owned()));
+    assert_eq!(answers.get(17, 2), Some(&Answer::from(117440)));
+    assert_eq!(answers.get(18, 2), None);
+    assert_eq!(answers.to_string(), "[day17]\npart2 = 117440\n");
+}
+
+#[test]
+fn reads_hand_written_files() {
+    let text = "# accepted answers\n\n[day03]\npart1 = 161\n  part2=48\n\n[day23]\npart2 = \"co,de,ka,ta\"\n";
+    let answers = Answers::parse(text).unwrap();
+    assert_eq!(answers.get(3, 1), Some(&Answer::from(161)));
+    assert_eq!(answers.get(3, 2), Some(&Answer::from(48)));
+    assert_eq!(answers.get(23, 1), None);
+    assert_eq!(answers.get(23, 2), Some(&Answer::from("co,de,ka,ta")));
+}
+
+#[test]
+fn points_at_mistakes() {
+    let error = Answers::parse("[day01]\npart3 = 5\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:2:1: Expected part1 or part2 but found 'part3'");
+
+    let error = Answers::parse("part1 = 5\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:1: Expected a [dayNN] table before the first answer");
+
+    let error = Answers::parse("[day01]\npart1 = \"open\n").unwrap_err();
+    assert_eq!(error.to_string(), "<input>:2:9: Expected a closing '\"'");
+}
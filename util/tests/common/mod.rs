@@ -0,0 +1,10 @@
+// shared by the tests that need somewhere on disk, each test file includes it as a module
+use std::fs;
+use std::path::PathBuf;
+
+// an empty directory path in the system's temp dir, named so tests running at once don't collide
+pub fn scratch(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    return path;
+}
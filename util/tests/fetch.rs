@@ -0,0 +1,94 @@
+// the cache is checked against a local stand-in for the puzzle site, nothing here touches the network
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+use util::fetch::{checksum, FetchError, Fetched, InputCache, CHECKSUMS_FILE};
+
+mod common;
+use common::scratch;
+
+// answers one request per response, in order, and hands back the request lines and headers it got
+fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let mut requests: Vec<Vec<String>> = vec![];
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request: Vec<String> = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() { break; }
+                request.push(line.trim().to_owned());
+            }
+            requests.push(request);
+            write!(stream, "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+        }
+        return requests;
+    });
+    return (url, server);
+}
+
+#[test]
+fn inputs_are_downloaded_once() {
+    let dir = scratch("fetch-once");
+    let (url, server) = serve(vec![(200, "3   4\n4   3\n")]);
+    let cache = InputCache::new(&dir, 2024).with_base_url(&url).with_session(Some("abc123".to_owned()));
+
+    assert_eq!(cache.fetch(1).unwrap(), Fetched::Downloaded);
+    // the stand-in only answers once, a second download would fail
+    assert_eq!(cache.fetch(1).unwrap(), Fetched::Cached);
+
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0][0], "GET /2024/day/1/input HTTP/1.1");
+    assert!(requests[0].iter().any(|it| it.eq_ignore_ascii_case("cookie: session=abc123")), "{:?}", requests);
+    assert_eq!(fs::read_to_string(dir.join("day01.txt")).unwrap(), "3   4\n4   3\n");
+    assert_eq!(fs::read_to_string(dir.join(CHECKSUMS_FILE)).unwrap(), format!("{}  day01.txt\n", checksum(b"3   4\n4   3\n")));
+    // both are written to a temporary file first, which is renamed rather than left behind
+    let mut names: Vec<String> = fs::read_dir(&dir).unwrap().map(|it| it.unwrap().file_name().to_string_lossy().into_owned()).collect();
+    names.sort();
+    assert_eq!(names, [CHECKSUMS_FILE, "day01.txt"]);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn edited_inputs_fail_the_checksum() {
+    let dir = scratch("fetch-edited");
+    let (url, server) = serve(vec![(200, "1\n10\n100\n2024\n")]);
+    let cache = InputCache::new(&dir, 2024).with_base_url(&url).with_session(Some("abc123".to_owned()));
+    cache.fetch(22).unwrap();
+    server.join().unwrap();
+
+    fs::write(dir.join("day22.txt"), "1\n10\n").unwrap();
+    let error = cache.fetch(22).unwrap_err();
+    assert!(matches!(error, FetchError::Checksum { .. }), "{}", error);
+
+    // inputs put in place by hand were never downloaded, so there is nothing to check them against
+    fs::write(dir.join("day05.txt"), "47|53\n").unwrap();
+    assert_eq!(cache.fetch(5).unwrap(), Fetched::Cached);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn failed_downloads_leave_nothing_behind() {
+    let dir = scratch("fetch-failed");
+    let (url, server) = serve(vec![(404, "Please don't repeatedly request this endpoint before it unlocks!")]);
+    // no session means the server isn't even asked
+    let error = InputCache::new(&dir, 2024).with_base_url(&url).with_session(None).fetch(25).unwrap_err();
+    assert!(matches!(error, FetchError::NoSession), "{}", error);
+
+    let cache = InputCache::new(&dir, 2024).with_base_url(&url).with_session(Some("abc123".to_owned()));
+    let error = cache.fetch(25).unwrap_err();
+    assert_eq!(error.to_string(), format!("{}/2024/day/25/input returned 404: Please don't repeatedly request this endpoint before it unlocks!", url));
+    assert_eq!(server.join().unwrap().len(), 1);
+    assert!(!dir.join("day25.txt").exists());
+
+    // nothing is listening once the stand-in has stopped
+    let error = cache.fetch(25).unwrap_err();
+    assert!(matches!(error, FetchError::Transport { .. }), "{}", error);
+}
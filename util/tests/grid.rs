@@ -0,0 +1,68 @@
+use util::{Grid, Point};
+
+// 3 wide and 2 tall
+// abc
+// def
+fn letters() -> Grid<char> {
+    return Grid::from_rows(vec!["abc".chars().collect(), "def".chars().collect()]).unwrap();
+}
+
+#[test]
+fn rows_must_match() {
+    assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    let empty: Grid<u8> = Grid::from_rows(vec![]).unwrap();
+    assert_eq!((empty.width(), empty.height()), (0, 0));
+    assert_eq!(empty.to_string(), "");
+}
+
+#[test]
+fn transpose_and_rotate_non_square() {
+    let grid = letters();
+    let transposed = grid.transpose();
+    assert_eq!((transposed.width(), transposed.height()), (2, 3));
+    assert_eq!(transposed.to_string(), "ad\nbe\ncf");
+    assert_eq!(transposed.transpose(), grid);
+
+    let clockwise = grid.rotate_clockwise();
+    assert_eq!((clockwise.width(), clockwise.height()), (2, 3));
+    assert_eq!(clockwise.to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+
+    // a full turn either way is back where it started
+    assert_eq!(clockwise.rotate_clockwise().rotate_clockwise().rotate_clockwise(), grid);
+    assert_eq!(clockwise.rotate_counter_clockwise(), grid);
+}
+
+#[test]
+fn neighbors_stay_on_the_grid() {
+    let grid = letters();
+    // clockwise from up, skipping the ones off the edge
+    assert_eq!(grid.neighbors((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbors((2, 1)).collect::<Vec<_>>(), vec![(2, 0), (1, 1)]);
+    assert_eq!(grid.neighbors((1, 0)).collect::<Vec<_>>(), vec![(2, 0), (1, 1), (0, 0)]);
+    assert_eq!(grid.neighbors_8((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (1, 1), (0, 1)]);
+    assert_eq!(grid.neighbors_8((1, 1)).count(), 5);
+
+    let single = Grid::new(1, 1, '.');
+    assert_eq!(single.neighbors((0, 0)).count(), 0);
+    assert_eq!(single.neighbors_8((0, 0)).count(), 0);
+}
+
+#[test]
+fn out_of_bounds() {
+    let mut grid = letters();
+    assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+    for point in [Point::new(3, 0), Point::new(0, 2), Point::new(-1, 0), Point::new(0, -1), Point::new(i64::MIN, i64::MAX)] {
+        assert_eq!(grid.get(point), None, "{}", point);
+        assert!(grid.get_mut(point).is_none(), "{}", point);
+        assert!(!grid.contains(point), "{}", point);
+    }
+    *grid.get_mut(Point::new(0, 1)).unwrap() = 'x';
+    assert_eq!(grid[(0, 1)], 'x');
+}
+
+#[test]
+#[should_panic(expected = "3,0 is outside a 3x2 grid")]
+fn indexing_off_the_edge_panics() {
+    let _ = letters()[Point::new(3, 0)];
+}
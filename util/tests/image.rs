@@ -0,0 +1,64 @@
+use std::fs;
+
+use util::image::{save_frames, Format, Image};
+use util::random::Rng;
+use util::viz::Frame;
+use util::{Grid, Point};
+
+mod common;
+use common::scratch;
+
+fn decode(bytes: &[u8]) -> Image {
+    let mut reader = png::Decoder::new(bytes).read_info().unwrap();
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).unwrap();
+    assert_eq!(info.color_type, png::ColorType::Rgb);
+    let mut image = Image::new(info.width as usize, info.height as usize, [0, 0, 0]);
+    for (index, pixel) in buffer[..info.buffer_size()].chunks(3).enumerate() {
+        image.set(index % image.width(), index / image.width(), [pixel[0], pixel[1], pixel[2]]);
+    }
+    return image;
+}
+
+#[test]
+fn pngs_decode_to_the_same_pixels() {
+    // noise can't be compressed, a grid mostly can, both have to come back exactly
+    let mut rng = Rng::new(1);
+    let mut noise = Image::new(37, 23, [0, 0, 0]);
+    for y in 0..23 {
+        for x in 0..37 {
+            noise.set(x, y, [rng.below(256) as u8, rng.below(4) as u8, 255]);
+        }
+    }
+    assert_eq!(decode(&noise.to_png()), noise);
+
+    let grid = Grid::from_vec(3, 2, "#..S.E".chars().collect());
+    let frame = Image::from_frame(&Frame::new(grid).with_path([Point::new(1, 1)]), 40);
+    let png = frame.to_png();
+    assert_eq!(decode(&png), frame);
+    assert!(png.len() < frame.to_ppm().len() / 20, "{} bytes", png.len());
+}
+
+#[test]
+fn overlays_color_their_cells() {
+    let frame = Frame::new(Grid::new(2, 2, '.')).with_visited([Point::new(0, 0), Point::new(1, 0)]).with_highlights([Point::new(1, 0)]);
+    let image = Image::from_frame(&frame, 2);
+    assert_eq!((image.width(), image.height()), (4, 4));
+    // the highlight wins over the shading, and all of a cell's pixels are the same
+    assert_eq!(image.get(2, 0), image.get(3, 1));
+    assert_ne!(image.get(0, 0), image.get(2, 0));
+    assert_ne!(image.get(0, 0), image.get(0, 2));
+    assert_eq!(&image.to_ppm()[..11], b"P6\n4 4\n255\n");
+    assert_eq!(image.to_ppm().len(), 11 + 4 * 4 * 3);
+}
+
+#[test]
+fn frames_are_numbered_in_order() {
+    let dir = scratch("image");
+    let frames = (0..3).map(|it| Frame::new(Grid::new(it + 1, 1, '#')));
+    let paths = save_frames(frames, &dir, Format::Ppm, 1).unwrap();
+    let names: Vec<String> = paths.iter().map(|it| it.file_name().unwrap().to_string_lossy().into_owned()).collect();
+    assert_eq!(names, ["frame-00000.ppm", "frame-00001.ppm", "frame-00002.ppm"]);
+    assert!(fs::read(&paths[2]).unwrap().starts_with(b"P6\n3 1\n"));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use util::json::{answer_line, error_line, quote};
+use util::{Answer, Usage};
+
+#[test]
+fn answers_keep_their_type() {
+    let elapsed = Duration::from_micros(5);
+    assert_eq!(
+        answer_line(7, 1, &Answer::from(3749), elapsed, None),
+        r#"{"day":7,"part":1,"answer":3749,"type":"integer","elapsed_ns":5000}"#,
+    );
+    assert_eq!(
+        answer_line(23, 2, &Answer::from("co,de,ka,ta"), elapsed, None),
+        r#"{"day":23,"part":2,"answer":"co,de,ka,ta","type":"string","elapsed_ns":5000}"#,
+    );
+    assert_eq!(
+        answer_line(18, 2, &Answer::Coordinate(6, 1), elapsed, None),
+        r#"{"day":18,"part":2,"answer":[6,1],"type":"coordinate","elapsed_ns":5000}"#,
+    );
+    // a part without an answer has an error instead
+    assert_eq!(
+        answer_line(18, 2, &Answer::Unsolved("The exit is never cut off".to_owned()), elapsed, None),
+        r#"{"day":18,"part":2,"error":"The exit is never cut off","elapsed_ns":5000}"#,
+    );
+}
+
+#[test]
+fn counted_allocations_are_included() {
+    let usage = Usage { allocations: 12, peak_bytes: 4096 };
+    assert_eq!(
+        answer_line(9, 1, &Answer::from(1928), Duration::from_nanos(750), Some(usage)),
+        r#"{"day":9,"part":1,"answer":1928,"type":"integer","elapsed_ns":750,"allocations":12,"peak_bytes":4096}"#,
+    );
+}
+
+#[test]
+fn strings_are_escaped() {
+    assert_eq!(quote("say \"hi\"\\\n"), r#""say \"hi\"\\\n""#);
+    assert_eq!(quote("\u{1}"), r#""\u0001""#);
+    assert_eq!(error_line(3, "bad\tinput"), r#"{"day":3,"error":"bad\tinput"}"#);
+}
@@ -0,0 +1,44 @@
+use util::{Direction, Point};
+
+#[test]
+fn turns_and_reverses() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.reverse().reverse(), direction);
+        assert_ne!(direction.reverse(), direction);
+        assert_eq!(direction.reverse().offset(), -direction.offset());
+        assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        assert_eq!(direction.is_vertical(), !direction.turn_right().is_vertical());
+    }
+    // ALL is clockwise, so turning right is the next one along
+    for (index, direction) in Direction::ALL.iter().enumerate() {
+        assert_eq!(direction.turn_right(), Direction::ALL[(index + 1) % 4]);
+    }
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::Left.reverse(), Direction::Right);
+}
+
+#[test]
+fn directions_from_arrows() {
+    for direction in Direction::ALL {
+        assert_eq!(Direction::try_from(direction.to_char()), Ok(direction));
+    }
+    // anything else is handed back
+    for character in ['.', '#', 'V', 'A', ' ', '→'] {
+        assert_eq!(Direction::try_from(character), Err(character));
+    }
+}
+
+#[test]
+fn moving() {
+    let mut point = Point::new(2, 3);
+    point += Direction::Up;
+    assert_eq!(point, Point::new(2, 2));
+    assert_eq!(point + Direction::Right - Direction::Down, Point::new(3, 1));
+    assert_eq!(Point::new(1, -2) * 3, Point::new(3, -6));
+    assert_eq!(Point::new(-1, 4).manhattan(Point::new(2, 0)), 7);
+    assert_eq!(Point::new(-1, 0).to_index(), None);
+    assert_eq!(Point::new(4, 5).to_index(), Some((4, 5)));
+    assert_eq!(Point::new(4, -5).to_string(), "4,-5");
+}
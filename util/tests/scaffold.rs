@@ -0,0 +1,42 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use util::scaffold::{new_day, new_year};
+
+mod common;
+use common::scratch;
+
+#[test]
+fn days_are_registered_in_order() {
+    let root = scratch("scaffold-order");
+    let util = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let created = new_year(&root, 2025, util).unwrap();
+    assert_eq!(created.iter().filter(|it| it.ends_with("src/lib.rs")).count(), 1);
+    assert!(fs::read_to_string(root.join("Cargo.toml")).unwrap().contains("name = \"advent-of-code-2025\""));
+
+    new_day(&root, 12).unwrap();
+    new_day(&root, 4).unwrap();
+    let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+    assert!(lib.starts_with("pub mod day01;\npub mod day04;\npub mod day12;\n\n"), "{}", lib);
+    assert!(lib.contains("        1 => Some(util::solve::<day01::Day01>),\n        4 => Some(util::solve::<day04::Day04>),\n        12 => Some(util::solve::<day12::Day12>),\n        _ => None,\n"), "{}", lib);
+
+    let examples = fs::read_to_string(root.join("tests/examples.rs")).unwrap();
+    let order: Vec<usize> = ["fn day01()", "fn day04()", "fn day12()"].iter().map(|it| examples.find(it).unwrap()).collect();
+    assert!(order.is_sorted(), "{}", examples);
+    assert_eq!(fs::read_to_string(root.join("src/bin/day04.rs")).unwrap(), "use advent_of_code_2025::day04::Day04;\n\nfn main() {\n    util::run::<Day04>(4);\n}\n");
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn existing_days_are_left_alone() {
+    let root = scratch("scaffold-existing");
+    new_year(&root, 2025, Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+    fs::write(root.join("src/day01.rs"), "solved").unwrap();
+
+    assert_eq!(new_day(&root, 1).unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(new_day(&root, 26).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(new_year(&root, 2025, Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(fs::read_to_string(root.join("src/day01.rs")).unwrap(), "solved");
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use util::random::check;
+use util::search::{astar, bfs, dijkstra};
+
+// 0 -> 1 -> 3 and 0 -> 2 -> 3 cost the same, 0 -> 4 -> 3 costs more
+fn diamond(node: &u32) -> Vec<(u32, usize)> {
+    return match node {
+        0 => vec![(1, 1), (2, 1), (4, 1)],
+        1 | 2 => vec![(3, 1)],
+        4 => vec![(3, 5)],
+        _ => vec![],
+    };
+}
+
+// the numbers from 0 to 10 in a line
+fn line(node: &i32) -> Vec<i32> {
+    return [node - 1, node + 1].into_iter().filter(|it| (0..=10).contains(it)).collect();
+}
+
+#[test]
+fn ties_keep_every_predecessor() {
+    let search = dijkstra([0], diamond, |it| *it == 3);
+    assert_eq!(search.goal_distance(), Some(2));
+    let mut previous = search.predecessors[&3].clone();
+    previous.sort();
+    assert_eq!(previous, vec![1, 2]);
+    assert_eq!(search.on_best_paths(&3), HashSet::from([0, 1, 2, 3]));
+    let path = search.path().unwrap();
+    assert!(path == vec![0, 1, 3] || path == vec![0, 2, 3], "{:?}", path);
+}
+
+#[test]
+fn unreachable_nodes_have_no_path() {
+    let search = dijkstra([0], diamond, |it| *it == 9);
+    assert_eq!(search.goal(), None);
+    assert_eq!(search.goal_distance(), None);
+    assert_eq!(search.path(), None);
+    assert_eq!(search.path_to(&9), None);
+    assert!(search.on_best_paths(&9).is_empty());
+    // everything that can be reached still is
+    assert_eq!(search.path_to(&4), Some(vec![0, 4]));
+}
+
+#[test]
+fn starting_on_a_goal() {
+    let search = bfs([5], line, |it| *it == 5);
+    assert_eq!(search.goals, vec![5]);
+    assert_eq!(search.goal_distance(), Some(0));
+    assert_eq!(search.path(), Some(vec![5]));
+
+    let search = dijkstra([3], diamond, |it| *it == 3);
+    assert_eq!(search.goal_distance(), Some(0));
+    assert_eq!(search.path(), Some(vec![3]));
+}
+
+#[test]
+fn several_starts() {
+    // 7 is closer to 10 than to 0
+    let search = bfs([0, 10], line, |it| *it == 7);
+    assert_eq!(search.goal_distance(), Some(3));
+    assert_eq!(search.path(), Some(vec![10, 9, 8, 7]));
+    assert_eq!(search.distance(&2), Some(2));
+
+    // either start being a goal finishes at once
+    let search = bfs([0, 10], line, |it| *it == 10);
+    assert_eq!(search.goal_distance(), Some(0));
+
+    let search = dijkstra([1, 4], diamond, |it| *it == 3);
+    assert_eq!(search.goal_distance(), Some(1));
+    assert_eq!(search.path(), Some(vec![1, 3]));
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    // a 6x6 grid where entering each tile costs 1-9, from the top left to the bottom right
+    check(50, |rng| {
+        let size = 6;
+        let costs: Vec<usize> = (0..size * size).map(|_| rng.range(1..=9) as usize).collect();
+        let successors = |&(x, y): &(usize, usize)| {
+            let mut next = vec![];
+            if x > 0 { next.push((x - 1, y)); }
+            if y > 0 { next.push((x, y - 1)); }
+            if x + 1 < size { next.push((x + 1, y)); }
+            if y + 1 < size { next.push((x, y + 1)); }
+            return next.into_iter().map(|(x, y)| ((x, y), costs[y * size + x])).collect::<Vec<_>>();
+        };
+        // every step costs at least 1, so the manhattan distance is consistent
+        let heuristic = |&(x, y): &(usize, usize)| (size - 1 - x) + (size - 1 - y);
+        let goal = |it: &(usize, usize)| *it == (size - 1, size - 1);
+
+        let expected = dijkstra([(0, 0)], successors, goal);
+        let search = astar([(0, 0)], successors, heuristic, goal);
+        if search.goal_distance() != expected.goal_distance() {
+            return Err(format!("A* found {:?} but dijkstra found {:?}", search.goal_distance(), expected.goal_distance()));
+        }
+        // the path it gives has to cost what it says
+        let path = search.path().unwrap();
+        let cost: usize = path[1..].iter().map(|(x, y)| costs[y * size + x]).sum();
+        if Some(cost) != search.goal_distance() {
+            return Err(format!("The path {:?} costs {} not {:?}", path, cost, search.goal_distance()));
+        }
+        return Ok(());
+    });
+}
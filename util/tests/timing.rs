@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+use util::timing::{format_duration, Stats};
+
+#[test]
+fn stats_from_samples() {
+    let millis = |values: &[u64]| values.iter().map(|it| Duration::from_millis(*it)).collect::<Vec<_>>();
+    let odd = Stats::from_samples(&millis(&[5, 1, 3])).unwrap();
+    assert_eq!(odd, Stats { runs: 3, min: Duration::from_millis(1), median: Duration::from_millis(3), max: Duration::from_millis(5) });
+
+    // an even number of runs takes the mean of the middle two
+    let even = Stats::from_samples(&millis(&[4, 1, 2, 8])).unwrap();
+    assert_eq!(even.median, Duration::from_millis(3));
+    assert_eq!(Stats::from_samples(&[]), None);
+}
+
+#[test]
+fn durations_pick_a_unit() {
+    assert_eq!(format_duration(Duration::from_micros(412)), "412.0 µs");
+    assert_eq!(format_duration(Duration::from_micros(3250)), "3.25 ms");
+    assert_eq!(format_duration(Duration::from_millis(1250)), "1.25 s");
+}
@@ -0,0 +1,46 @@
+use util::viz::{Animation, Frame};
+use util::{Grid, Point};
+
+fn frame() -> Frame {
+    return Frame::from_rows(vec!["#..".chars().collect(), "...".chars().collect()]).unwrap();
+}
+
+#[test]
+fn overlays_replace_cells_without_color() {
+    let frame = frame()
+        .with_visited([(1usize, 0usize), (2, 0)])
+        .with_path([Point::new(2, 0), Point::new(2, 1)])
+        // later overlays win, and cells off the grid are ignored
+        .with_highlights([Point::new(2, 1), Point::new(5, 5)])
+        .with_caption("Step 1");
+    assert_eq!(frame.render(false), "#XO\n..*\nStep 1\n");
+    assert!(Frame::from_rows(vec![vec!['#'], vec![]]).is_none());
+}
+
+#[test]
+fn colors_only_change_where_the_style_does() {
+    let frame = Frame::new(Grid::new(3, 1, '.')).with_visited([Point::new(0, 0), Point::new(1, 0)]).with_path([Point::new(1, 0)]);
+    assert_eq!(frame.render(true), "\x1b[0;44m.\x1b[0;1;33;44m.\x1b[0m.\n");
+    assert_eq!(Frame::new(Grid::new(2, 1, '.')).render(true), "..\n");
+}
+
+#[test]
+fn still_animations_draw_the_last_frame() {
+    let frames = (0..3).map(|it| frame().with_caption(format!("Step {}", it)));
+    let mut output: Vec<u8> = vec![];
+    assert_eq!(Animation::still().with_color(false).play(frames, &mut output).unwrap(), 3);
+    assert_eq!(String::from_utf8(output).unwrap(), "#..\n...\nStep 2\n");
+}
+
+#[test]
+fn animations_draw_every_frame() {
+    let frames = (0..2).map(|it| frame().with_caption(format!("Step {}", it)));
+    let mut output: Vec<u8> = vec![];
+    Animation::new(1000.0).with_color(false).play(frames.clone(), &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "#..\n...\nStep 0\n\n#..\n...\nStep 1\n");
+
+    // in color each frame is drawn over the last one
+    let mut output: Vec<u8> = vec![];
+    Animation::new(1000.0).with_color(true).play(frames, &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\x1b[2J\x1b[H#..\n...\nStep 0\x1b[K\n\x1b[H#..\n...\nStep 1\x1b[K\n");
+}